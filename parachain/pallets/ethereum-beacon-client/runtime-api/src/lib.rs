@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use snowbridge_beacon_primitives::{
+	CompactBeaconState, CompactExecutionHeader, EquivocationEvidence, Update,
+};
+use sp_core::H256;
+use sp_runtime::DispatchError;
+
+sp_api::decl_runtime_apis! {
+	pub trait EthereumBeaconClientApi
+	{
+		/// Block root of the latest finalized beacon header.
+		fn latest_finalized_block_root() -> H256;
+
+		/// State of the latest finalized beacon header, including its slot.
+		fn latest_finalized_beacon_state() -> Option<CompactBeaconState>;
+
+		/// Sync committee period of the latest finalized beacon header.
+		fn current_sync_committee_period() -> Option<u64>;
+
+		/// Whether the sync committee for the next period is known.
+		fn next_sync_committee_known() -> bool;
+
+		/// Imported execution header with the given block hash.
+		fn execution_header(block_hash: H256) -> Option<CompactExecutionHeader>;
+
+		/// Evidence of conflicting finality which halted the light client, if any.
+		fn equivocation() -> Option<EquivocationEvidence>;
+
+		/// Verifies an update without applying it, so that it can be checked before submission.
+		fn verify_update(update: Update) -> Result<(), DispatchError>;
+	}
+}
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+//! Helpers for implementing runtime api
+
+use frame_support::{dispatch::DispatchError, ensure};
+use primitives::{CompactBeaconState, CompactExecutionHeader, EquivocationEvidence};
+use sp_core::H256;
+
+use crate::{
+	functions::compute_period, types::Update, Config, Equivocation, Error, ExecutionHeaders,
+	FinalizedBeaconState, LatestFinalizedBlockRoot, NextSyncCommittee, Pallet,
+};
+
+pub fn latest_finalized_block_root<Runtime, I>() -> H256
+where
+	Runtime: Config<I>,
+	I: 'static,
+{
+	LatestFinalizedBlockRoot::<Runtime, I>::get()
+}
+
+pub fn latest_finalized_beacon_state<Runtime, I>() -> Option<CompactBeaconState>
+where
+	Runtime: Config<I>,
+	I: 'static,
+{
+	FinalizedBeaconState::<Runtime, I>::get(LatestFinalizedBlockRoot::<Runtime, I>::get())
+}
+
+pub fn current_sync_committee_period<Runtime, I>() -> Option<u64>
+where
+	Runtime: Config<I>,
+	I: 'static,
+{
+	latest_finalized_beacon_state::<Runtime, I>()
+		.map(|state| compute_period::<Runtime::BeaconSpec>(state.slot))
+}
+
+pub fn next_sync_committee_known<Runtime, I>() -> bool
+where
+	Runtime: Config<I>,
+	I: 'static,
+{
+	NextSyncCommittee::<Runtime, I>::exists()
+}
+
+pub fn execution_header<Runtime, I>(block_hash: H256) -> Option<CompactExecutionHeader>
+where
+	Runtime: Config<I>,
+	I: 'static,
+{
+	ExecutionHeaders::<Runtime, I>::get(block_hash)
+}
+
+pub fn equivocation<Runtime, I>() -> Option<EquivocationEvidence>
+where
+	Runtime: Config<I>,
+	I: 'static,
+{
+	Equivocation::<Runtime, I>::get()
+}
+
+/// Performs the same checks as `submit` without applying the update.
+pub fn verify_update<Runtime, I>(update: &Update) -> Result<(), DispatchError>
+where
+	Runtime: Config<I>,
+	I: 'static,
+{
+	ensure!(!Pallet::<Runtime, I>::is_halted(), Error::<Runtime, I>::Halted);
+	Pallet::<Runtime, I>::verify_update(update)
+}
@@ -3,8 +3,8 @@
 use crate::{CheckpointUpdate, ExecutionHeaderUpdate, Update};
 use hex_literal::hex;
 use primitives::{
-	updates::AncestryProof, BeaconHeader, ExecutionPayloadHeader, NextSyncCommitteeUpdate,
-	SyncAggregate, SyncCommittee,
+	updates::AncestryProof, BeaconHeader, ExecutionBlockRoots, NextSyncCommitteeUpdate,
+	SyncAggregate, SyncCommittee, VersionedExecutionPayloadHeader,
 };
 use sp_core::U256;
 use sp_std::{boxed::Box, vec};
@@ -19,7 +19,7 @@ pub fn make_checkpoint() -> Box<CheckpointUpdate> {
             body_root: hex!("8c799aeef815cbc4499e0b46723623105afb177a5c522ecda3415ad9fb259e6c").into(),
         },
         current_sync_committee: SyncCommittee {
-            pubkeys: [
+            pubkeys: vec![
                 hex!("adf5a4907639db7bdcbecbc295b57d8950b0abe34ab17798686643427023c4f3983550d1496f81a27e52b070e4f4e6ee").into(),
                 hex!("91b036b30405531cacebf5d4f7e939b44438bb9942123ee55b44453e32febfbf2c846e0e4fb08190b01a000d072dcaa7").into(),
                 hex!("a86e70f00161ec6c4b780b4fc631c8dbae979f1e6c9ed037dd0745833ed6e3e18831478eb4753861f339293c0508f4d4").into(),
@@ -532,7 +532,7 @@ pub fn make_checkpoint() -> Box<CheckpointUpdate> {
                 hex!("92edce89bea01fdb25da5d0f903de2fee626681cec3db418a9161286a2e95bbb90ef2ccd8dad434b51776f85d982700a").into(),
                 hex!("aefc7dea295547984ab42a64f2f59ba2ae8220712778a71530623351a44372ddd1018cd8d4951934ae0fa39653ad6aae").into(),
                 hex!("ab297f28266bd5ed104c1b55088f114592e80aa098a0865e5543e12c6392b0f94b5cd4e0b6f375d1a0d0809d80c1fca0").into(),
-            ],
+            ].try_into().unwrap(),
             aggregate_pubkey: hex!("81a5778df2e724c98b4ef79ff33b9c5fa3ea265de81d49de5c4ab3be2165d32fe15c59c982f758c3b9c522ca5e659fce").into(),
         },
         current_sync_committee_branch: vec![
@@ -551,6 +551,7 @@ pub fn make_checkpoint() -> Box<CheckpointUpdate> {
             hex!("0ccefa47e43d03e26def9fa07bacd91a5a2a20c6c5dec2ea090f71f91ac99282").into(),
             hex!("f03f3d7a52241ab959560beb9b748a8ab93e2b7221c8070561a12a5fba8d4434").into(),
         ],
+        next_sync_committee_update: None,
     })
 }
 
@@ -564,13 +565,13 @@ pub fn make_sync_committee_update() -> Box<Update> {
             body_root: hex!("3d248ca71ec98250b8dcdeab1207806406f1434c11874655af56925da6bd88da").into(),
         },
         sync_aggregate: SyncAggregate{
-            sync_committee_bits: hex!("ffabbff6fcdefbebaefffff9e37dfffebff57f7bffe3efbdfef1f7f987751dd176f3b3ff7bfa3fedff5fdf7f7afff7ff777bef5f9f7fe75f97fffe7dfdfffbdf"),
+            sync_committee_bits: hex!("ffabbff6fcdefbebaefffff9e37dfffebff57f7bffe3efbdfef1f7f987751dd176f3b3ff7bfa3fedff5fdf7f7afff7ff777bef5f9f7fe75f97fffe7dfdfffbdf").to_vec().try_into().unwrap(),
             sync_committee_signature: hex!("b405701a0227b7c40805504a66069fb5ef99cdd84f1e295c9b4a4eccbe4d93718740efa9f8eca62f563dbc73021c00e914a69b00a9ebaa906e78f26c1cb8088af916096801c787f18f493b1479fd43f1f5b28d15af827a1e580713fa82bfa1d7").into(),
         },
         signature_slot: 5808575,
         next_sync_committee_update: Some(NextSyncCommitteeUpdate {
             next_sync_committee: SyncCommittee {
-                pubkeys: [
+                pubkeys: vec![
                     hex!("8e9fbd36b3cbaaefc176cf46336592e2b59a51e3035d095da9e1df9d2fb5aac5e47ad05d27784ca675442abb875a6559").into(),
                     hex!("b4c6164c5ea19f3da5a76a2435db598bb012ea34cc8fb6d749f1588463e5c39d29cb3d45ceae0543372246549b17deaa").into(),
                     hex!("a89c780da1a713e86b149d63312aa840e865dd926565f0ee9d9627d363eadadf5a4bd5f79d8039f2e2927ed7fa60209f").into(),
@@ -1083,7 +1084,7 @@ pub fn make_sync_committee_update() -> Box<Update> {
                     hex!("957cd40aa0864b86bc64420a184988be4489c0f0a3363f39571e71a7443ac6815a1b8ce4862c736be8441108dd78101b").into(),
                     hex!("8f31cdb655b66e1f8ad877639f71524aa78c09acc24aa493bd6f6be383c295f51a6e70f2573081cf87cd41ef55f5428d").into(),
                     hex!("a8f304c1f2faa78683d409dbb0c11e26583fdfe845f2557e378c56acc9baaa88cce25442733edcdd0da70f3e5557e53b").into(),
-                ],
+                ].try_into().unwrap(),
                 aggregate_pubkey: hex!("870e9dfe2c909b7116a9a4180da4fb6ac4865f9304adc4c36dde6f82338c43352b58dfb494e6095bfade1dbf86e7f939").into(),
             },
             next_sync_committee_branch: vec![
@@ -1130,7 +1131,7 @@ pub fn make_finalized_header_update() -> Box<Update> {
             body_root: hex!("406c96c6adad01df901df3625cbd622f1d541249b05c768ccc4db5643d973141").into(),
         },
         sync_aggregate: SyncAggregate{
-            sync_committee_bits: hex!("7fabbff6fcdefbebaefffff9e37dfffebff57f7bf3e3efbcfef1f7f987551dd176f3b3ff7bfa3fedff5fdf7f7afff5ff777bef5f9f7fe65f97fffe7dfdfffbdd").into(),
+            sync_committee_bits: hex!("7fabbff6fcdefbebaefffff9e37dfffebff57f7bf3e3efbcfef1f7f987551dd176f3b3ff7bfa3fedff5fdf7f7afff5ff777bef5f9f7fe65f97fffe7dfdfffbdd").to_vec().try_into().unwrap(),
             sync_committee_signature: hex!("84dc756c452ec9a3ba01cc98d03cf5471b871e9f3f77ddfe72ddf6d5d318ec3de9e5c1508e47ed362300cd45a144655a076d50073c24a67591b0454d2a4632bc01e97eab80f937a8288131a31ab76f400ba9c26a19df176c7e67b724f70407c3").into(),
         },
         signature_slot: 5809445,
@@ -1164,52 +1165,59 @@ pub fn make_finalized_header_update() -> Box<Update> {
 pub fn make_execution_header_update() -> Box<ExecutionHeaderUpdate> {
 	Box::new(ExecutionHeaderUpdate {
         header: BeaconHeader {
-            slot: 5809374,
-            proposer_index: 130336,
-            parent_root: hex!("2bb54c61560a80d1cfb0528e8ea207dfb9d55ab49238523e21609a9ee3b8a9b5").into(),
-            state_root: hex!("0116ea76f5c36373dfc8e039811eba86c8e8e16cfe9f0614376559b6585741a7").into(),
-            body_root: hex!("9a10b47e30bc11fc2ee1e21943a8382d727444f646f09664192236458b555ffe").into(),
+            slot: 7413792,
+            proposer_index: 5,
+            parent_root: hex!("18e726b153aca8cb5d8269dcf0825a7a97d091cf2a54ab8e91a6edb4844962a8").into(),
+            state_root: hex!("655d6712a8cef2471b4297aecb06916931140879ddc34b1628fc9a947cb6b078").into(),
+            body_root: hex!("13273c44ee09c529fe356948b31f0cc65562159f92d7bb9c3037ea56c38646a9").into(),
         },
-        ancestry_proof: Some(AncestryProof {
+        ancestry_proof: Some(AncestryProof::BlockRoots {
             header_branch: vec![
-                hex!("eca009f3262f75b055e6c919e2c0a2c017f017e581a825a2618a2a76926a264e").into(),
-                hex!("a647371a5590630186dd47b9b8571f27e39a77b4aac1f763fabefe104bf94985").into(),
-                hex!("9a414690540e4c87ff5171b619b3ab6ff1115c21f247196989f5a0a9085b59a1").into(),
-                hex!("1bf7ae16fcde0833c6e97a83b72aef31a0b5ca055b87f86602b9b4aa193f557c").into(),
-                hex!("588d993d05b59bf3352f0f5ebb4cd3ec97ca3e41800da675996741e8fca374c0").into(),
-                hex!("fdfc6280944bb0a18c9cd0afa9f4a255719a4650233f19de478399276f198c92").into(),
-                hex!("1d96235b47c604f029b9ab7eb913b13b3c0c2df7f79e3301341b1ec38ea44e4c").into(),
-                hex!("3ee3af17ce8f5a4946d30b6bce7d6e7580b3981cd2af92246401e2326224f6d1").into(),
-                hex!("b53b5450e070adf02f4bb9d7c65dd131d07ae2218340eec95ac8aa5e5cdd82aa").into(),
-                hex!("4d7c09715a1f25574afa1dc3dd7bb44e4c1a723b9360c893b8510f675f85227a").into(),
-                hex!("38c159fc38dedc1e4f399a3f773ab4376fc40b126634b40d172d5daa6602cf94").into(),
-                hex!("9faac6fa44ed19fcf530f77b7090dd50dd17aeedabe763931ab7567276025a75").into(),
-                hex!("c6549c1b0f0027ac373164437e7010b955fbae1a0e78485408ec33ca906beb2d").into(),
+                hex!("35500e8fd1ee54b5490e91fb386d6214a0a699faf56dae59daef8f9a1aa6717c").into(),
+                hex!("9c4edbf7925024fc902cd119e41a50a2fb80a2dcbbcff8ba2c50c05d04d4592a").into(),
+                hex!("a9caca2a523f5e5132232bb6bb1bd5b1b78b8302ca5f9b6e7babbe7641d84254").into(),
+                hex!("8c872d5ecff24f4450899cb6de09bdc8d055d3bb73a731b5032023ad3b804347").into(),
+                hex!("635308f22f75039ebbdf6ed57346223f11e06751b591aeead5fe3380c68dccde").into(),
+                hex!("7b4f326d01404a14b1399dfbed60068437a203e6488db5ea2dff133342957228").into(),
+                hex!("55501e45ce49712e74717aa5de2fa24ecb79a43d7f99f1d3ff68dd716c4b7ed6").into(),
+                hex!("abb4d35db114604927b889d28ad805eb676c32a7be5cc2180d7eebf6412ed1ae").into(),
+                hex!("e0d636c02909d98b1a0437d15caf31eba5a022fb0407a0bfb57341d41cbe201d").into(),
+                hex!("ed35bdb3a4e317deeffc69cee57fcbe5c28dbe3b4c6f928ac08e6a79c0faee53").into(),
+                hex!("aad0be8afa9f9d814c805f6cea322c8eb59a9b418f07e942ee506c88a59ab95d").into(),
+                hex!("8b3c4d79ab7b91c4ff34e479aa8660b1aebd1c50bac20da829eae143f25c3230").into(),
+                hex!("7f54c0cb111b8e5144abffc00089fedff91dcebb3b3cac424159b50f30b36bba").into(),
             ],
-            finalized_block_root: hex!("f6e721e4e65d9565091a557705285ec6db0a3a3072317317719ec8ad563859a3").into(),
+            finalized_block_root: hex!("a8f41606062e989cdf45b30ba095ea007aeef636942b32e9075e2b0cbab1b890").into(),
+        }),
+        execution_header: VersionedExecutionPayloadHeader::Deneb(primitives::types::deneb::ExecutionPayloadHeader {
+            parent_hash: hex!("33148b0473e87f61d47342134ed25f024720d4d6feb4cda1fdbf6bf84e0db2ef").into(),
+            fee_recipient: hex!("0000000000000000000000000000000000000000").into(),
+            state_root: hex!("96a83e9ddf745346fafcb0b03d57314623df669ed543c110662b21302a0fae8b").into(),
+            receipts_root: hex!("a1d3d826583ed389eb3625d1f99c47e524fb260f73188a43ffb496c4b268192f").into(),
+            logs_bloom: hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000800100000000000000000000020000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000400000000000000000000000000000000").into(),
+            prev_randao: hex!("62e309d4f5119d1f5c783abc20fc1a549efbab546d8d0b25ff1cfd58be524e67").into(),
+            block_number: 10396384,
+            gas_limit: 54492273,
+            gas_used: 29000,
+            timestamp: 1710552813,
+            extra_data: hex!("d983010d0b846765746888676f312e32312e368664617277696e").into(),
+            base_fee_per_gas: U256::from(7 as u64),
+            block_hash: hex!("8fcc7a22f6cb6436329015eb3a2df0b6ea759cb286f63843f129a6c37bb82484").into(),
+            transactions_root: hex!("ebbfecc96b891a25bb00c03c055bed116aeb4161aa9f3742d71cd336e36ad344").into(),
+            withdrawals_root: hex!("792930bbd5baac43bcc798ee49aa8185ef76bb3b44ba62b91d86ae569e4bb535").into(),
+            blob_gas_used: 0,
+            excess_blob_gas: 786432,
         }),
-        execution_header: ExecutionPayloadHeader {
-            parent_hash: hex!("6d51d7c94763813ffefa234097a51c6fd7009424d2991695f7bd6203157c86f9").into(),
-            fee_recipient: hex!("000095e79eac4d76aab57cb2c1f091d553b36ca0").into(),
-            state_root: hex!("fe9f753520a7b5c0263bbf4fdba728f69e9cf861ce1883aa13de5da30ff75d74").into(),
-            receipts_root: hex!("cf6ab47d8fc336155b18abfa2d965aae57d9d35a2fcf5cfc992b8dcd136958cb").into(),
-            logs_bloom: hex!("8427414fce71480d7e70cdbac68dd6f77608c05cf349c34c87ad3256e8dde9e3f9c52131945876c03b6e83ea5970536428283a180eb40efcc5fd834ce424f0dbf622dbba6cfda7945cc1f93a1b6e7ae448c598b4f45f7cfa933fe9808d835cb86e8a38261a031448e262f8e4f2dc4c3254c460e5faae4b518438c1330012154a1ba33ab7d85c8acaa9c47dc582fd003a771c9b09aa16c34d4f0c01fbb3f8c0a28e11d2eafb4e73b75a18e182eac7c021706832a9a785836d31f651efacf88a329334e5b3def3bf1871573dc3553f415f298a9457f7837a31302937a4178be1339cdbb83af329ae7e88d8ab6cba62f018be139896ecbc7ac11ef24b0b4ae343e9").into(),
-            prev_randao: hex!("5a76eff974d26bf74dc3003fac473ab4abc541be26bd61f124a1818a70ea0b3e").into(),
-            block_number: 9143323,
-            gas_limit: 30000000,
-            gas_used: 28165724,
-            timestamp: 1686220488,
-            extra_data: hex!("").into(),
-            base_fee_per_gas: U256::from(2267 as u64),
-            block_hash: hex!("e4a67cdb1512f29ad9b331e7a37cf8e376222eafa58e72cee7771ad582cc0610").into(),
-            transactions_root: hex!("bd7eaeb676c14c37bbf0b6f3db2ce021a04a41dbf002f6c7df3bb61639ac7287").into(),
-            withdrawals_root: hex!("8647d3ecaaf62e1d087c5ab54a23f1d64f477b7ddd16fff458847181d89fc432").into(),
-        },
         execution_branch: vec![
-            hex!("795608ac1294bcc663127b8428513ba4a5ffe952ff72f8322dca23628f13d716").into(),
-            hex!("336488033fe5f3ef4ccc12af07b9370b92e553e35ecb4a337a1b1c0e4afe1e0e").into(),
-            hex!("db56114e00fdd4c1f85c892bf35ac9a89289aaecb1ebd0a96cde606a748b5d71").into(),
-            hex!("2a8f5c65655edeb2800f248f2e14044fc651061d0c00c8e8b627cb21ba421fb4").into(),
+            hex!("409bc7f083c2dfab996c203bd5b09ca8039183482cca91dd311d730868ed221c").into(),
+            hex!("ff4b887c072dc036a37d97a46a3861d1be89b7fe5379857bbc8b3fc6e19522dc").into(),
+            hex!("8da8a01911c82f17baad655162073d0be2d348788ea855d1d50498a943be6c09").into(),
+            hex!("f44499dbc20f16513c45b81b3c2178ca99f4790cec1399842ca12db5f4af67d8").into(),
         ],
+        execution_block_roots: ExecutionBlockRoots {
+            transactions_root: hex!("0c2c5c06a00f643d84e42ea6f4924ab190d00f964f94d8587020e249526a8e8c").into(),
+            withdrawals_root: hex!("56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421").into(),
+            requests_hash: None,
+        },
     })
 }
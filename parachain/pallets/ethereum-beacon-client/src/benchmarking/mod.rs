@@ -14,13 +14,15 @@ use fixtures::{
 	make_sync_committee_update,
 };
 
+use hex_literal::hex;
 use primitives::{
-	fast_aggregate_verify, prepare_aggregate_pubkey, prepare_aggregate_signature,
-	verify_merkle_branch,
+	fast_aggregate_verify, fast_aggregate_verify_host,
+	merkle_proof::{generalized_index_length, subtree_index},
+	prepare_aggregate_pubkey, prepare_aggregate_signature, verify_merkle_branch,
 };
 use util::*;
 
-#[benchmarks]
+#[instance_benchmarks]
 mod benchmarks {
 	use super::*;
 
@@ -32,8 +34,8 @@ mod benchmarks {
 		#[extrinsic_call]
 		_(RawOrigin::Root, *checkpoint_update);
 
-		assert!(<LatestFinalizedBlockRoot<T>>::get() == block_root);
-		assert!(<FinalizedBeaconState<T>>::get(block_root).is_some());
+		assert!(<LatestFinalizedBlockRoot<T, I>>::get() == block_root);
+		assert!(<FinalizedBeaconState<T, I>>::get(block_root).is_some());
 
 		Ok(())
 	}
@@ -44,13 +46,13 @@ mod benchmarks {
 		let checkpoint_update = make_checkpoint();
 		let finalized_header_update = make_finalized_header_update();
 		let block_root: H256 = finalized_header_update.finalized_header.hash_tree_root().unwrap();
-		EthereumBeaconClient::<T>::process_checkpoint_update(&checkpoint_update)?;
+		EthereumBeaconClient::<T, I>::process_checkpoint_update(&checkpoint_update)?;
 
 		#[extrinsic_call]
 		submit(RawOrigin::Signed(caller.clone()), *finalized_header_update);
 
-		assert!(<LatestFinalizedBlockRoot<T>>::get() == block_root);
-		assert!(<FinalizedBeaconState<T>>::get(block_root).is_some());
+		assert!(<LatestFinalizedBlockRoot<T, I>>::get() == block_root);
+		assert!(<FinalizedBeaconState<T, I>>::get(block_root).is_some());
 
 		Ok(())
 	}
@@ -60,12 +62,12 @@ mod benchmarks {
 		let caller: T::AccountId = whitelisted_caller();
 		let checkpoint_update = make_checkpoint();
 		let sync_committee_update = make_sync_committee_update();
-		EthereumBeaconClient::<T>::process_checkpoint_update(&checkpoint_update)?;
+		EthereumBeaconClient::<T, I>::process_checkpoint_update(&checkpoint_update)?;
 
 		#[extrinsic_call]
 		submit(RawOrigin::Signed(caller.clone()), *sync_committee_update);
 
-		assert!(<NextSyncCommittee<T>>::exists());
+		assert!(<NextSyncCommittee<T, I>>::exists());
 
 		Ok(())
 	}
@@ -74,26 +76,65 @@ mod benchmarks {
 	fn submit_execution_header() -> Result<(), BenchmarkError> {
 		let caller: T::AccountId = whitelisted_caller();
 		let checkpoint_update = make_checkpoint();
-		let finalized_header_update = make_finalized_header_update();
 		let execution_header_update = make_execution_header_update();
-		let execution_header_hash = execution_header_update.execution_header.block_hash;
-		EthereumBeaconClient::<T>::process_checkpoint_update(&checkpoint_update)?;
-		EthereumBeaconClient::<T>::process_update(&finalized_header_update)?;
+		let execution_header_hash = execution_header_update.execution_header.block_hash();
+		EthereumBeaconClient::<T, I>::process_checkpoint_update(&checkpoint_update)?;
+		// The execution header update is proven against a finalized header in the Deneb fork,
+		// which is stored directly rather than through a signed update.
+		let finalized_block_root = match &execution_header_update.ancestry_proof {
+			Some(AncestryProof::BlockRoots { finalized_block_root, .. }) => *finalized_block_root,
+			_ => return Err(BenchmarkError::Stop("Expected a block roots ancestry proof")),
+		};
+		<FinalizedBeaconState<T, I>>::insert(
+			finalized_block_root,
+			CompactBeaconState {
+				slot: execution_header_update.header.slot + 32,
+				block_roots_root: hex!(
+					"b5404c9856bb39c4406e58345bdee3a899d0e05f77ef39d519a99a97664c562e"
+				)
+				.into(),
+			},
+		);
+		<FinalizedBlockRoots<T, I>>::insert(
+			execution_header_update.header.slot + 32,
+			finalized_block_root,
+		);
+		<LatestFinalizedBlockRoot<T, I>>::set(finalized_block_root);
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()), *execution_header_update);
 
-		assert!(<ExecutionHeaders<T>>::contains_key(execution_header_hash));
+		assert!(<ExecutionHeaders<T, I>>::contains_key(execution_header_hash));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_update() -> Result<(), BenchmarkError> {
+		let checkpoint_update = make_checkpoint();
+		let sync_committee_update = make_sync_committee_update();
+		EthereumBeaconClient::<T, I>::process_checkpoint_update(&checkpoint_update)?;
+		// The largest update to read and apply is one carrying the next sync committee.
+		<BestValidUpdate<T, I>>::set(Some((*sync_committee_update, 0u32.into())));
+		frame_system::Pallet::<T>::set_block_number(T::UpdateTimeout::get() + 1u32.into());
+
+		#[block]
+		{
+			EthereumBeaconClient::<T, I>::process_force_update()?;
+		}
+
+		assert!(<BestValidUpdate<T, I>>::get().is_none());
+		assert!(<NextSyncCommittee<T, I>>::exists());
 
 		Ok(())
 	}
 
 	#[benchmark(extra)]
 	fn bls_fast_aggregate_verify_pre_aggregated() -> Result<(), BenchmarkError> {
-		EthereumBeaconClient::<T>::process_checkpoint_update(&make_checkpoint())?;
+		EthereumBeaconClient::<T, I>::process_checkpoint_update(&make_checkpoint())?;
 		let update = make_sync_committee_update();
-		let participant_pubkeys = participant_pubkeys::<T>(&update)?;
-		let signing_root = signing_root::<T>(&update)?;
+		let participant_pubkeys = participant_pubkeys::<T, I>(&update)?;
+		let signing_root = signing_root::<T, I>(&update)?;
 		let agg_sig =
 			prepare_aggregate_signature(&update.sync_aggregate.sync_committee_signature).unwrap();
 		let agg_pub_key = prepare_aggregate_pubkey(&participant_pubkeys).unwrap();
@@ -108,11 +149,11 @@ mod benchmarks {
 
 	#[benchmark(extra)]
 	fn bls_fast_aggregate_verify() -> Result<(), BenchmarkError> {
-		EthereumBeaconClient::<T>::process_checkpoint_update(&make_checkpoint())?;
+		EthereumBeaconClient::<T, I>::process_checkpoint_update(&make_checkpoint())?;
 		let update = make_sync_committee_update();
-		let current_sync_committee = <CurrentSyncCommittee<T>>::get();
-		let absent_pubkeys = absent_pubkeys::<T>(&update)?;
-		let signing_root = signing_root::<T>(&update)?;
+		let current_sync_committee = <CurrentSyncCommittee<T, I>>::get();
+		let absent_pubkeys = absent_pubkeys::<T, I>(&update)?;
+		let signing_root = signing_root::<T, I>(&update)?;
 
 		#[block]
 		{
@@ -128,9 +169,68 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark(extra)]
+	fn bls_fast_aggregate_verify_host() -> Result<(), BenchmarkError> {
+		EthereumBeaconClient::<T, I>::process_checkpoint_update(&make_checkpoint())?;
+		let update = make_sync_committee_update();
+		let current_sync_committee = <CurrentSyncCommittee<T, I>>::get();
+		let absent_pubkeys = absent_compressed_pubkeys::<T, I>(&update);
+		let signing_root = signing_root::<T, I>(&update)?;
+
+		#[block]
+		{
+			fast_aggregate_verify_host(
+				&current_sync_committee.aggregate_pubkey,
+				&absent_pubkeys,
+				signing_root,
+				&update.sync_aggregate.sync_committee_signature,
+			)
+			.unwrap();
+		}
+
+		Ok(())
+	}
+
+	#[benchmark(extra)]
+	fn read_current_sync_committee() -> Result<(), BenchmarkError> {
+		EthereumBeaconClient::<T, I>::process_checkpoint_update(&make_checkpoint())?;
+
+		#[block]
+		{
+			<CurrentSyncCommittee<T, I>>::get();
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_operating_mode() -> Result<(), BenchmarkError> {
+		#[extrinsic_call]
+		_(RawOrigin::Root, Mode::Blocked);
+
+		assert!(<OperatingMode<T, I>>::get() == Mode::Blocked);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_fork_schedule() -> Result<(), BenchmarkError> {
+		EthereumBeaconClient::<T, I>::process_checkpoint_update(&make_checkpoint())?;
+		let origin = T::ForkScheduleOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let fork_versions = T::ForkVersions::get();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, fork_versions.clone());
+
+		assert!(<ForkSchedule<T, I>>::get() == fork_versions);
+
+		Ok(())
+	}
+
 	#[benchmark(extra)]
 	fn verify_merkle_proof() -> Result<(), BenchmarkError> {
-		EthereumBeaconClient::<T>::process_checkpoint_update(&make_checkpoint())?;
+		EthereumBeaconClient::<T, I>::process_checkpoint_update(&make_checkpoint())?;
 		let update = make_sync_committee_update();
 		let block_root: H256 = update.finalized_header.hash_tree_root().unwrap();
 
@@ -139,8 +239,8 @@ mod benchmarks {
 			verify_merkle_branch(
 				block_root,
 				&update.finality_branch,
-				config::FINALIZED_ROOT_SUBTREE_INDEX,
-				config::FINALIZED_ROOT_DEPTH,
+				subtree_index(config::altair::FINALIZED_ROOT_INDEX),
+				generalized_index_length(config::altair::FINALIZED_ROOT_INDEX),
 				update.attested_header.state_root,
 			);
 		}
@@ -1,41 +1,48 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
 use crate::{
-	decompress_sync_committee_bits, Config, CurrentSyncCommittee, Pallet as EthereumBeaconClient,
-	Update, ValidatorsRoot, Vec,
+	Config, CurrentSyncCommittee, Pallet as EthereumBeaconClient, Update, ValidatorsRoot, Vec,
+};
+use primitives::{
+	decompress_sync_committee_bits, prepare_g1_pubkeys, PublicKey, PublicKeyPrepared,
 };
-use primitives::PublicKeyPrepared;
 use sp_core::H256;
 
-pub fn participant_pubkeys<T: Config>(
+pub fn participant_pubkeys<T: Config<I>, I: 'static>(
 	update: &Update,
 ) -> Result<Vec<PublicKeyPrepared>, &'static str> {
 	let sync_committee_bits =
-		decompress_sync_committee_bits(update.sync_aggregate.sync_committee_bits);
-	let current_sync_committee = <CurrentSyncCommittee<T>>::get();
-	let pubkeys = EthereumBeaconClient::<T>::find_pubkeys(
+		decompress_sync_committee_bits(&update.sync_aggregate.sync_committee_bits);
+	let current_sync_committee = <CurrentSyncCommittee<T, I>>::get();
+	let pubkeys = EthereumBeaconClient::<T, I>::find_pubkeys(
 		&sync_committee_bits,
-		(*current_sync_committee.pubkeys).as_ref(),
+		&current_sync_committee.pubkeys,
 		true,
 	);
-	Ok(pubkeys)
+	prepare_g1_pubkeys(&pubkeys).map_err(|_| "Invalid sync committee public keys")
+}
+
+pub fn absent_pubkeys<T: Config<I>, I: 'static>(
+	update: &Update,
+) -> Result<Vec<PublicKeyPrepared>, &'static str> {
+	prepare_g1_pubkeys(&absent_compressed_pubkeys::<T, I>(update))
+		.map_err(|_| "Invalid sync committee public keys")
 }
 
-pub fn absent_pubkeys<T: Config>(update: &Update) -> Result<Vec<PublicKeyPrepared>, &'static str> {
+pub fn absent_compressed_pubkeys<T: Config<I>, I: 'static>(update: &Update) -> Vec<PublicKey> {
 	let sync_committee_bits =
-		decompress_sync_committee_bits(update.sync_aggregate.sync_committee_bits);
-	let current_sync_committee = <CurrentSyncCommittee<T>>::get();
-	let pubkeys = EthereumBeaconClient::<T>::find_pubkeys(
+		decompress_sync_committee_bits(&update.sync_aggregate.sync_committee_bits);
+	let current_sync_committee = <CurrentSyncCommittee<T, I>>::get();
+	EthereumBeaconClient::<T, I>::find_pubkeys(
 		&sync_committee_bits,
-		(*current_sync_committee.pubkeys).as_ref(),
+		&current_sync_committee.pubkeys,
 		false,
-	);
-	Ok(pubkeys)
+	)
 }
 
-pub fn signing_root<T: Config>(update: &Update) -> Result<H256, &'static str> {
-	let validators_root = <ValidatorsRoot<T>>::get();
-	let signing_root = EthereumBeaconClient::<T>::signing_root(
+pub fn signing_root<T: Config<I>, I: 'static>(update: &Update) -> Result<H256, &'static str> {
+	let validators_root = <ValidatorsRoot<T, I>>::get();
+	let signing_root = EthereumBeaconClient::<T, I>::signing_root(
 		&update.attested_header,
 		validators_root,
 		update.signature_slot,
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+//! Generalized indices for the beacon state and block body layouts from Altair up to Deneb.
+
+// get_generalized_index(BeaconState, 'block_roots')
+pub const BLOCK_ROOTS_INDEX: usize = 37;
+
+// get_generalized_index(BeaconState, 'finalized_checkpoint', 'root')
+pub const FINALIZED_ROOT_INDEX: usize = 105;
+
+// get_generalized_index(BeaconState, 'current_sync_committee')
+pub const CURRENT_SYNC_COMMITTEE_INDEX: usize = 54;
+
+// get_generalized_index(BeaconState, 'next_sync_committee')
+pub const NEXT_SYNC_COMMITTEE_INDEX: usize = 55;
+
+// get_generalized_index(BeaconState, 'historical_summaries'), from Capella
+pub const HISTORICAL_SUMMARIES_INDEX: usize = 59;
+
+// get_generalized_index(BeaconBlockBody, 'execution_payload')
+pub const EXECUTION_HEADER_INDEX: usize = 25;
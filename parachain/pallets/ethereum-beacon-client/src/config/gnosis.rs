@@ -0,0 +1,14 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+use super::BeaconSpec;
+
+/// The `gnosis` preset, used by Gnosis Chain and the Chiado testnet.
+pub struct Gnosis;
+
+impl BeaconSpec for Gnosis {
+	const SLOTS_PER_EPOCH: usize = 16;
+	const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize = 512;
+	const SYNC_COMMITTEE_SIZE: usize = primitives::config::MAINNET_SYNC_COMMITTEE_SIZE;
+	const SLOTS_PER_HISTORICAL_ROOT: usize = 8192;
+	const BLOCK_ROOT_AT_INDEX_DEPTH: usize = 13;
+}
@@ -1,10 +1,14 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
-pub const SLOTS_PER_EPOCH: usize = 8;
-pub const SECONDS_PER_SLOT: usize = 6;
-pub const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize = 8;
-pub const SYNC_COMMITTEE_SIZE: usize = 32;
-pub const SYNC_COMMITTEE_BITS_SIZE: usize = SYNC_COMMITTEE_SIZE / 8;
-pub const SLOTS_PER_HISTORICAL_ROOT: usize = 64;
-pub const IS_MINIMAL: bool = true;
-pub const BLOCK_ROOT_AT_INDEX_DEPTH: usize = 6;
+use super::BeaconSpec;
+
+/// The `minimal` preset, used by local testnets.
+pub struct Minimal;
+
+impl BeaconSpec for Minimal {
+	const SLOTS_PER_EPOCH: usize = 8;
+	const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize = 8;
+	const SYNC_COMMITTEE_SIZE: usize = primitives::config::MINIMAL_SYNC_COMMITTEE_SIZE;
+	const SLOTS_PER_HISTORICAL_ROOT: usize = 64;
+	const BLOCK_ROOT_AT_INDEX_DEPTH: usize = 6;
+}
@@ -2,23 +2,40 @@
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
 use super::*;
 
-use frame_support::dispatch::DispatchError;
-use snowbridge_ethereum::{Log, Receipt};
+use frame_support::{dispatch::DispatchError, ensure};
+use primitives::{verify_account_proof, verify_storage_proof, verify_transaction_proof};
+use snowbridge_core::{StateVerifier, TransactionVerifier};
+use snowbridge_ethereum::{Account, Log, Receipt, Transaction, H160, U256};
 
-impl<T: Config> Verifier for Pallet<T> {
-	/// Verify a message by verifying the existence of the corresponding
-	/// Ethereum log in a block. Returns the log if successful.
+impl<T: Config<I>, I: 'static> Verifier for Pallet<T, I> {
+	/// Verify a message by verifying the inclusion of the receipt containing the
+	/// Ethereum log in a block. Returns the log at `proof.log_index` if successful.
 	fn verify(message: &Message) -> Result<Log, DispatchError> {
+		ensure!(!Self::is_halted(), Error::<T, I>::Halted);
+
 		log::info!(
 			target: "ethereum-beacon-client",
 			"💫 Verifying message with block hash {}",
 			message.proof.block_hash,
 		);
 
-		let header = <ExecutionHeaderBuffer<T>>::get(message.proof.block_hash)
-			.ok_or(Error::<T>::MissingHeader)?;
+		// The execution header is either proven inline, or must have been imported before.
+		let receipts_root = match &message.proof.execution_proof {
+			Some(execution_proof) => {
+				ensure!(
+					execution_proof.execution_header.block_hash() == message.proof.block_hash,
+					Error::<T, I>::InvalidProof
+				);
+				Self::verify_execution_header_update(execution_proof)?;
+				execution_proof.execution_header.receipts_root()
+			},
+			None =>
+				<ExecutionHeaderBuffer<T, I>>::get(message.proof.block_hash)
+					.ok_or(Error::<T, I>::MissingHeader)?
+					.receipts_root,
+		};
 
-		let receipt = match Self::verify_receipt_inclusion(header.receipts_root, &message.proof) {
+		let receipt = match Self::verify_receipt_inclusion(receipts_root, &message.proof) {
 			Ok(receipt) => receipt,
 			Err(err) => {
 				log::error!(
@@ -37,28 +54,28 @@ impl<T: Config> Verifier for Pallet<T> {
 			message.proof.tx_index, message.proof.block_hash,
 		);
 
-		let log = match rlp::decode(&message.data) {
-			Ok(log) => log,
-			Err(err) => {
-				log::error!(
-					target: "ethereum-beacon-client",
-					"💫 RLP log decoded failed {}: {:?}",
-					message.proof.block_hash,
-					err
-				);
-				return Err(Error::<T>::DecodeFailed.into())
-			},
-		};
-
-		if !receipt.contains_log(&log) {
+		// Logs of reverted transactions are still committed to the receipts trie (EIP-658).
+		if !receipt.is_success() {
 			log::error!(
 				target: "ethereum-beacon-client",
-				"💫 Event log not found in receipt for transaction at index {} in block {}",
+				"💫 Transaction at index {} in block {} failed",
 				message.proof.tx_index, message.proof.block_hash,
 			);
-			return Err(Error::<T>::InvalidProof.into())
+			return Err(Error::<T, I>::TransactionFailed.into())
 		}
 
+		let log = match receipt.logs.get(message.proof.log_index as usize) {
+			Some(log) => log.clone(),
+			None => {
+				log::error!(
+					target: "ethereum-beacon-client",
+					"💫 No log at index {} in receipt for transaction at index {} in block {}",
+					message.proof.log_index, message.proof.tx_index, message.proof.block_hash,
+				);
+				return Err(Error::<T, I>::InvalidProof.into())
+			},
+		};
+
 		log::info!(
 			target: "ethereum-beacon-client",
 			"💫 Receipt verification successful for {}",
@@ -69,15 +86,109 @@ impl<T: Config> Verifier for Pallet<T> {
 	}
 }
 
-impl<T: Config> Pallet<T> {
-	/// Verifies that the receipt encoded in `proof.data` is included in the block given by
-	/// `proof.block_hash`. Inclusion is only recognized if the block has been finalized.
+impl<T: Config<I>, I: 'static> StateVerifier for Pallet<T, I> {
+	/// Verify an account proof against the state root of an imported execution header.
+	fn verify_account(
+		block_hash: H256,
+		address: H160,
+		account_proof: &[Vec<u8>],
+	) -> Result<Option<Account>, DispatchError> {
+		ensure!(!Self::is_halted(), Error::<T, I>::Halted);
+
+		let state_root = <ExecutionHeaderBuffer<T, I>>::get(block_hash)
+			.ok_or(Error::<T, I>::MissingHeader)?
+			.state_root;
+
+		verify_account_proof(state_root, address, account_proof).map_err(|err| {
+			log::error!(
+				target: "ethereum-beacon-client",
+				"💫 Verification of account {} failed for block {}: {:?}",
+				address,
+				block_hash,
+				err
+			);
+			Error::<T, I>::InvalidProof.into()
+		})
+	}
+
+	/// Verify a storage proof against the storage root of an account, which is itself proven
+	/// against the state root of an imported execution header.
+	fn verify_storage(
+		block_hash: H256,
+		address: H160,
+		account_proof: &[Vec<u8>],
+		key: H256,
+		storage_proof: &[Vec<u8>],
+	) -> Result<U256, DispatchError> {
+		// The storage of an account which does not exist is empty.
+		let account = match Self::verify_account(block_hash, address, account_proof)? {
+			Some(account) => account,
+			None => return Ok(U256::zero()),
+		};
+
+		verify_storage_proof(account.storage_root, key, storage_proof).map_err(|err| {
+			log::error!(
+				target: "ethereum-beacon-client",
+				"💫 Verification of storage slot {} of account {} failed for block {}: {:?}",
+				key,
+				address,
+				block_hash,
+				err
+			);
+			Error::<T, I>::InvalidProof.into()
+		})
+	}
+}
+
+impl<T: Config<I>, I: 'static> TransactionVerifier for Pallet<T, I> {
+	/// Verify a transaction proof against the transactions root of an imported execution header.
+	fn verify_transaction(
+		block_hash: H256,
+		tx_index: u32,
+		proof: &[Vec<u8>],
+	) -> Result<Transaction, DispatchError> {
+		ensure!(!Self::is_halted(), Error::<T, I>::Halted);
+
+		let transactions_root = <ExecutionHeaderBuffer<T, I>>::get(block_hash)
+			.ok_or(Error::<T, I>::MissingHeader)?
+			.transactions_root
+			.ok_or(Error::<T, I>::MissingTransactionsRoot)?;
+
+		let result =
+			verify_transaction_proof(transactions_root, tx_index, proof).ok_or_else(|| {
+				log::error!(
+					target: "ethereum-beacon-client",
+					"💫 Verification of transaction at index {} failed for block {}",
+					tx_index,
+					block_hash,
+				);
+				Error::<T, I>::InvalidProof
+			})?;
+
+		result.map_err(|err| {
+			log::error!(
+				target: "ethereum-beacon-client",
+				"💫 Failed to decode transaction at index {} in block {}: {:?}",
+				tx_index,
+				block_hash,
+				err
+			);
+			Error::<T, I>::DecodeFailed.into()
+		})
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Verifies that the receipt of the transaction at `proof.tx_index` is included in the block
+	/// given by `proof.block_hash`, using the trie nodes in `proof.data`. The trie keys are derived
+	/// from `proof.tx_index`, so the keys in `proof.data` are not needed. Inclusion is only
+	/// recognized if the block has been finalized.
 	pub fn verify_receipt_inclusion(
 		receipts_root: H256,
 		proof: &Proof,
 	) -> Result<Receipt, DispatchError> {
-		let result =
-			verify_receipt_proof(receipts_root, &proof.data.1).ok_or(Error::<T>::InvalidProof)?;
+		let result = verify_receipt_proof(receipts_root, proof.tx_index, &proof.data.1)
+			.ok_or(Error::<T, I>::InvalidProof)?;
 
 		match result {
 			Ok(receipt) => Ok(receipt),
@@ -87,7 +198,7 @@ impl<T: Config> Pallet<T> {
 					"💫 Failed to decode transaction receipt: {}",
 					err
 				);
-				Err(Error::<T>::InvalidProof.into())
+				Err(Error::<T, I>::InvalidProof.into())
 			},
 		}
 	}
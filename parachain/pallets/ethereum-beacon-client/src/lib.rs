@@ -1,31 +1,49 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
 //! Ethereum Beacon Client
+//!
+//! The pallet is instantiable, so a runtime can follow several Ethereum networks, each with its
+//! own fork versions, consensus preset and storage.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod api;
 pub mod config;
 pub mod functions;
 pub mod impls;
+pub mod migration;
 pub mod types;
 pub mod weights;
 
 #[cfg(test)]
 mod mock;
-#[cfg(all(test, not(feature = "beacon-spec-mainnet")))]
+#[cfg(test)]
 mod tests;
+#[cfg(test)]
+mod tests_mainnet;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
 use frame_support::{
-	dispatch::DispatchResult, log, pallet_prelude::OptionQuery, traits::Get, transactional,
+	dispatch::{DispatchResult, DispatchResultWithPostInfo, Pays},
+	log,
+	pallet_prelude::OptionQuery,
+	storage::with_storage_layer,
+	traits::Get,
+	transactional,
 };
 use frame_system::ensure_signed;
+#[cfg(feature = "bls-host-function")]
+use primitives::fast_aggregate_verify_host as fast_aggregate_verify;
 use primitives::{
-	fast_aggregate_verify, verify_merkle_branch, verify_receipt_proof, BeaconHeader, BlsError,
-	CompactBeaconState, CompactExecutionHeader, ExecutionHeaderState, ForkData, ForkVersion,
-	ForkVersions, PublicKeyPrepared, SigningData,
+	decompress_sync_committee_bits,
+	merkle_proof::{generalized_index_length, subtree_index},
+	verify_merkle_branch, verify_receipt_proof, BeaconHeader, BlsError, CompactBeaconState,
+	CompactExecutionHeader, EquivocationEvidence, ExecutionHeaderState, ForkData, ForkVersion,
+	ForkVersions, Mode, PublicKey, SigningData, VersionedExecutionPayloadHeader,
 };
+#[cfg(not(feature = "bls-host-function"))]
+use primitives::{fast_aggregate_verify, prepare_g1_pubkeys};
 use snowbridge_core::{Message, RingBufferMap, Verifier};
 use sp_core::H256;
 use sp_std::prelude::*;
@@ -33,17 +51,15 @@ pub use weights::WeightInfo;
 
 use snowbridge_core::Proof;
 
-use functions::{
-	compute_epoch, compute_period, decompress_sync_committee_bits, sync_committee_sum,
-};
+use functions::{compute_epoch, compute_period, sync_committee_sum};
 use types::{
-	CheckpointUpdate, ExecutionHeaderBuffer, ExecutionHeaderUpdate, FinalizedBeaconStateBuffer,
-	SyncCommitteePrepared, Update,
+	AncestryProof, CheckpointUpdate, ExecutionHeaderBuffer, ExecutionHeaderUpdate,
+	FinalizedBeaconStateBuffer, FinalizedBlockRootBuffer, SyncCommitteePrepared, Update,
 };
 
 pub use pallet::*;
 
-pub use config::SLOTS_PER_HISTORICAL_ROOT;
+pub use config::{gnosis::Gnosis, mainnet::Mainnet, minimal::Minimal, BeaconSpec};
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -53,44 +69,97 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode, codec::MaxEncodedLen)]
-	#[codec(mel_bound(T: Config))]
-	#[scale_info(skip_type_params(T))]
-	pub struct MaxFinalizedHeadersToKeep<T: Config>(PhantomData<T>);
-	impl<T: Config> Get<u32> for MaxFinalizedHeadersToKeep<T> {
+	#[codec(mel_bound(T: Config<I>, I: 'static))]
+	#[scale_info(skip_type_params(T, I))]
+	pub struct MaxFinalizedHeadersToKeep<T: Config<I>, I: 'static = ()>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> Get<u32> for MaxFinalizedHeadersToKeep<T, I> {
 		fn get() -> u32 {
-			// Consider max latency allowed between LatestFinalizedState and LatestExecutionState is
-			// the total slots in one sync_committee_period so 1 should be fine we keep 2 periods
-			// here for redundancy.
+			// Finalized states are kept for ancestry proofs of execution headers against their
+			// `block_roots`. Older execution headers are proven through historical summaries, so
+			// keeping 2 sync committee periods of finalized states is plenty.
 			const MAX_REDUNDANCY: u32 = 2;
-			config::EPOCHS_PER_SYNC_COMMITTEE_PERIOD as u32 * MAX_REDUNDANCY
+			T::BeaconSpec::EPOCHS_PER_SYNC_COMMITTEE_PERIOD as u32 * MAX_REDUNDANCY
 		}
 	}
 
+	/// The in-code storage version.
+	pub(super) const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 	#[pallet::pallet]
-	pub struct Pallet<T>(_);
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Fork schedule of the Ethereum network, used until one is set at genesis or by
+		/// governance
 		#[pallet::constant]
 		type ForkVersions: Get<ForkVersions>;
+		/// Origin allowed to update the fork schedule
+		type ForkScheduleOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// Maximum number of execution headers to keep
 		#[pallet::constant]
 		type MaxExecutionHeadersToKeep: Get<u32>;
+		/// Consensus preset of the Ethereum network followed by the light client
+		type BeaconSpec: BeaconSpec;
+		/// Maximum number of fee-free updates accepted in a single block
+		#[pallet::constant]
+		type MaxFreeUpdatesPerBlock: Get<u32>;
+		/// Maximum number of updates in a batch, which bounds the weight of `submit_batch`
+		#[pallet::constant]
+		type MaxUpdatesPerBatch: Get<u32>;
+		/// Number of blocks of this chain without a newer finalized header, counted from the
+		/// block in which the best valid update was first kept, after which the best valid update
+		/// is applied regardless. Time is not measured in beacon slots, as the slots of updates
+		/// are chosen by the relayer.
+		#[pallet::constant]
+		type UpdateTimeout: Get<Self::BlockNumber>;
+		/// Number of slots before the latest finalized header within which checkpoints are
+		/// accepted. Older checkpoints are outside the weak subjectivity period.
+		#[pallet::constant]
+		type WeakSubjectivityPeriod: Get<u64>;
 		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		BeaconHeaderImported { block_hash: H256, slot: u64 },
-		ExecutionHeaderImported { block_hash: H256, block_number: u64 },
-		SyncCommitteeUpdated { period: u64 },
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		BeaconHeaderImported {
+			block_hash: H256,
+			slot: u64,
+		},
+		ExecutionHeaderImported {
+			block_hash: H256,
+			block_number: u64,
+		},
+		SyncCommitteeUpdated {
+			period: u64,
+		},
+		/// Set OperatingMode
+		OperatingModeChanged {
+			mode: Mode,
+		},
+		/// A validly signed update finalized a different header at an already finalized slot.
+		/// The light client is halted.
+		EquivocationDetected {
+			slot: u64,
+			finalized_block_root: H256,
+			conflicting_block_root: H256,
+		},
+		/// No header was finalized within the update timeout, so the best valid update was
+		/// applied, importing the header at `slot` as finalized.
+		UpdateForced {
+			slot: u64,
+		},
+		/// The fork schedule was updated by governance.
+		ForkScheduleUpdated,
 	}
 
 	#[pallet::error]
 	#[cfg_attr(test, derive(PartialEq))]
-	pub enum Error<T> {
+	pub enum Error<T, I = ()> {
 		SkippedSyncCommitteePeriod,
 		NotRelevant,
 		NotBootstrapped,
@@ -99,6 +168,7 @@ pub mod pallet {
 		InvalidSyncCommitteeMerkleProof,
 		InvalidExecutionHeaderProof,
 		InvalidAncestryMerkleProof,
+		HistoricalSummaryNotAvailable,
 		InvalidBlockRootsRootMerkleProof,
 		HeaderNotFinalized,
 		MissingHeader,
@@ -114,71 +184,175 @@ pub mod pallet {
 		BLSVerificationFailed(BlsError),
 		InvalidUpdateSlot,
 		InvalidSyncCommitteeUpdate,
-		ExecutionHeaderTooFarBehind,
-		ExecutionHeaderSkippedSlot,
+		ExecutionHeaderAlreadyImported,
+		InvalidExecutionHeaderVersion,
+		/// The pallet is currently halted by governance.
+		Halted,
+		/// The sync committee or its participation bits do not match the configured preset.
+		InvalidSyncCommitteeSize,
+		/// A batch must contain at least one update.
+		EmptyBatch,
+		/// The receipt containing the message log records a failed transaction.
+		TransactionFailed,
+		/// The transactions root of the execution header is not known.
+		MissingTransactionsRoot,
+		/// The execution block hash does not match the hash of the execution block header.
+		InvalidExecutionBlockHash,
+		/// The execution header does not extend the latest imported execution header.
+		InvalidExecutionHeaderParent,
+		/// The fork schedule rewrites an active fork, or is out of order.
+		InvalidForkSchedule,
+		/// The checkpoint is older than the weak subjectivity period allows.
+		StaleCheckpoint,
+		/// The header was imported by a forced update without being finalized, so execution
+		/// headers cannot be proven against it.
+		FinalizedHeaderForced,
 	}
 
 	/// Latest imported checkpoint root
 	#[pallet::storage]
 	#[pallet::getter(fn initial_checkpoint_root)]
-	pub(super) type InitialCheckpointRoot<T: Config> = StorageValue<_, H256, ValueQuery>;
+	pub(super) type InitialCheckpointRoot<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, H256, ValueQuery>;
 
 	/// Latest imported finalized block root
 	#[pallet::storage]
 	#[pallet::getter(fn latest_finalized_block_root)]
-	pub(super) type LatestFinalizedBlockRoot<T: Config> = StorageValue<_, H256, ValueQuery>;
+	pub(super) type LatestFinalizedBlockRoot<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, H256, ValueQuery>;
 
 	/// Beacon state by finalized block root
 	#[pallet::storage]
 	#[pallet::getter(fn finalized_beacon_state)]
-	pub(super) type FinalizedBeaconState<T: Config> =
+	pub(super) type FinalizedBeaconState<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Identity, H256, CompactBeaconState, OptionQuery>;
 
 	/// Finalized Headers: Current position in ring buffer
 	#[pallet::storage]
-	pub(crate) type FinalizedBeaconStateIndex<T: Config> = StorageValue<_, u32, ValueQuery>;
+	pub(crate) type FinalizedBeaconStateIndex<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u32, ValueQuery>;
 
 	/// Finalized Headers: Mapping of ring buffer index to a pruning candidate
 	#[pallet::storage]
-	pub(crate) type FinalizedBeaconStateMapping<T: Config> =
+	pub(crate) type FinalizedBeaconStateMapping<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Identity, u32, H256, ValueQuery>;
 
+	/// Finalized block roots by slot, kept for the same window as the finalized beacon states
+	/// to detect conflicting finality.
+	#[pallet::storage]
+	#[pallet::getter(fn finalized_block_root_at_slot)]
+	pub(super) type FinalizedBlockRoots<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, u64, H256, OptionQuery>;
+
+	/// Finalized Block Roots: Current position in ring buffer
+	#[pallet::storage]
+	pub(crate) type FinalizedBlockRootIndex<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u32, ValueQuery>;
+
+	/// Finalized Block Roots: Mapping of ring buffer index to a pruning candidate
+	#[pallet::storage]
+	pub(crate) type FinalizedBlockRootMapping<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, u32, u64, ValueQuery>;
+
+	/// Evidence of conflicting finality which halted the light client, kept for governance
+	/// review.
+	#[pallet::storage]
+	#[pallet::getter(fn equivocation)]
+	pub(super) type Equivocation<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, EquivocationEvidence, OptionQuery>;
+
+	/// The best valid update received since the latest finalized header was imported, along
+	/// with the number of the block in which a best valid update was first kept. The update is
+	/// applied if no newer header is finalized within `UpdateTimeout` blocks.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	#[pallet::getter(fn best_valid_update)]
+	pub(super) type BestValidUpdate<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (Update, T::BlockNumber), OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn validators_root)]
-	pub(super) type ValidatorsRoot<T: Config> = StorageValue<_, H256, ValueQuery>;
+	pub(super) type ValidatorsRoot<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, H256, ValueQuery>;
 
 	/// Sync committee for current period
 	#[pallet::storage]
-	pub(super) type CurrentSyncCommittee<T: Config> =
+	pub(super) type CurrentSyncCommittee<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, SyncCommitteePrepared, ValueQuery>;
 
 	/// Sync committee for next period
 	#[pallet::storage]
-	pub(super) type NextSyncCommittee<T: Config> =
+	pub(super) type NextSyncCommittee<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, SyncCommitteePrepared, ValueQuery>;
 
-	/// Latest imported execution header
+	/// Execution header with the highest block number imported so far. Execution headers are
+	/// imported sparsely, so headers below it are not necessarily imported.
 	#[pallet::storage]
 	#[pallet::getter(fn latest_execution_state)]
-	pub(super) type LatestExecutionState<T: Config> =
+	pub(super) type LatestExecutionState<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, ExecutionHeaderState, ValueQuery>;
 
 	/// Execution Headers
 	#[pallet::storage]
-	pub(super) type ExecutionHeaders<T: Config> =
+	pub(super) type ExecutionHeaders<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Identity, H256, CompactExecutionHeader, OptionQuery>;
 
 	/// Execution Headers: Current position in ring buffer
 	#[pallet::storage]
-	pub(crate) type ExecutionHeaderIndex<T: Config> = StorageValue<_, u32, ValueQuery>;
+	pub(crate) type ExecutionHeaderIndex<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u32, ValueQuery>;
 
 	/// Execution Headers: Mapping of ring buffer index to a pruning candidate
 	#[pallet::storage]
-	pub(crate) type ExecutionHeaderMapping<T: Config> =
+	pub(crate) type ExecutionHeaderMapping<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Identity, u32, H256, ValueQuery>;
 
+	/// Number of fee-free updates accepted in a block, along with that block's number. The
+	/// count is reset by the first fee-free update of a later block.
+	#[pallet::storage]
+	pub(super) type FreeUpdatesInBlock<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (T::BlockNumber, u32), ValueQuery>;
+
+	/// The current operating mode of the pallet.
+	#[pallet::storage]
+	#[pallet::getter(fn operating_mode)]
+	pub type OperatingMode<T: Config<I>, I: 'static = ()> = StorageValue<_, Mode, ValueQuery>;
+
+	/// Fork schedule of the Ethereum network, which defaults to `ForkVersions` until set.
+	#[pallet::storage]
+	#[pallet::getter(fn fork_schedule)]
+	pub type ForkSchedule<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, ForkVersions, ValueQuery, T::ForkVersions>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig {
+		pub fork_versions: Option<ForkVersions>,
+		/// Checkpoint to bootstrap the light client with, instead of a `force_checkpoint` call
+		pub initial_checkpoint: Option<CheckpointUpdate>,
+	}
+
+	impl Default for GenesisConfig {
+		fn default() -> Self {
+			Self { fork_versions: None, initial_checkpoint: None }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config<I>, I: 'static> GenesisBuild<T, I> for GenesisConfig {
+		fn build(&self) {
+			// The fork schedule determines the proofs expected from the checkpoint.
+			if let Some(fork_versions) = &self.fork_versions {
+				<ForkSchedule<T, I>>::put(fork_versions);
+			}
+			if let Some(checkpoint) = &self.initial_checkpoint {
+				Pallet::<T, I>::process_checkpoint_update(checkpoint)
+					.expect("invalid initial checkpoint");
+			}
+		}
+	}
+
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::force_checkpoint())]
 		#[transactional]
@@ -191,19 +365,18 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(1)]
-		#[pallet::weight({
-			match update.next_sync_committee_update {
-				None => T::WeightInfo::submit(),
-				Some(_) => T::WeightInfo::submit_with_sync_committee(),
-			}
-		})]
+		#[pallet::weight(Pallet::<T, I>::update_weight(update))]
 		#[transactional]
 		/// Submits a new finalized beacon header update. The update may contain the next
-		/// sync committee.
-		pub fn submit(origin: OriginFor<T>, update: Update) -> DispatchResult {
+		/// sync committee. Updates that advance the light client are free of charge.
+		pub fn submit(origin: OriginFor<T>, update: Update) -> DispatchResultWithPostInfo {
 			ensure_signed(origin)?;
-			Self::process_update(&update)?;
-			Ok(())
+			ensure!(!Self::is_halted(), Error::<T, I>::Halted);
+			let advanced = Self::process_update(&update)?;
+			if !advanced {
+				return Ok(Pays::Yes.into())
+			}
+			Ok(Self::consume_free_update().into())
 		}
 
 		#[pallet::call_index(2)]
@@ -211,208 +384,499 @@ pub mod pallet {
 		#[transactional]
 		/// Submits a new execution header update. The relevant related beacon header
 		/// is also included to prove the execution header, as well as ancestry proof data.
+		/// Importing an execution header newer than the latest imported one is free of charge.
 		pub fn submit_execution_header(
 			origin: OriginFor<T>,
 			update: ExecutionHeaderUpdate,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			ensure_signed(origin)?;
+			ensure!(!Self::is_halted(), Error::<T, I>::Halted);
+			let latest_block_number = <LatestExecutionState<T, I>>::get().block_number;
 			Self::process_execution_header_update(&update)?;
+			if update.execution_header.block_number() <= latest_block_number {
+				return Ok(Pays::Yes.into())
+			}
+			Ok(Self::consume_free_update().into())
+		}
+
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::set_operating_mode())]
+		/// Halt or resume all pallet operations. May only be called by root.
+		pub fn set_operating_mode(origin: OriginFor<T>, mode: Mode) -> DispatchResult {
+			ensure_root(origin)?;
+			OperatingMode::<T, I>::set(mode);
+			Self::deposit_event(Event::OperatingModeChanged { mode });
+			Ok(())
+		}
+
+		#[pallet::call_index(4)]
+		#[pallet::weight({
+			updates.iter().fold(Weight::zero(), |weight, update| {
+				weight.saturating_add(Pallet::<T, I>::update_weight(update))
+			})
+		})]
+		#[transactional]
+		/// Submits consecutive finalized beacon header updates, which are applied atomically.
+		/// Used to catch up after more than a sync committee period without updates, by chaining
+		/// updates that each carry the next sync committee. The batch is free of charge if every
+		/// update advances the light client.
+		pub fn submit_batch(
+			origin: OriginFor<T>,
+			updates: BoundedVec<Update, T::MaxUpdatesPerBatch>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(!Self::is_halted(), Error::<T, I>::Halted);
+			ensure!(!updates.is_empty(), Error::<T, I>::EmptyBatch);
+			let mut advanced = true;
+			for update in updates.iter() {
+				advanced &= Self::process_update(update)?;
+				// Stop at conflicting finality, keeping the evidence and the halt.
+				if Self::is_halted() {
+					break
+				}
+			}
+			if !advanced {
+				return Ok(Pays::Yes.into())
+			}
+			Ok(Self::consume_free_update().into())
+		}
+
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_fork_schedule())]
+		/// Sets the fork schedule, to schedule upcoming forks. Forks that are already active at
+		/// the latest finalized header cannot be rewritten. May only be called by
+		/// `ForkScheduleOrigin`.
+		pub fn set_fork_schedule(
+			origin: OriginFor<T>,
+			fork_versions: ForkVersions,
+		) -> DispatchResult {
+			T::ForkScheduleOrigin::ensure_origin(origin)?;
+			Self::verify_fork_schedule(&fork_versions)?;
+			<ForkSchedule<T, I>>::set(fork_versions);
+			Self::deposit_event(Event::ForkScheduleUpdated);
 			Ok(())
 		}
 	}
 
-	impl<T: Config> Pallet<T> {
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Forces a finalized beacon header checkpoint update. The current sync committee,
-		/// with a header attesting to the current sync committee, should be provided.
-		/// An `block_roots` proof should also be provided. This is used for ancestry proofs
-		/// for execution header updates.
+		/// with a header attesting to the current sync committee, should be provided, and
+		/// optionally the next sync committee. An `block_roots` proof should also be provided.
+		/// This is used for ancestry proofs for execution header updates. Checkpoints older than
+		/// the weak subjectivity period before the latest finalized header are rejected.
 		pub(crate) fn process_checkpoint_update(update: &CheckpointUpdate) -> DispatchResult {
+			if let Some(latest_finalized_state) =
+				FinalizedBeaconState::<T, I>::get(LatestFinalizedBlockRoot::<T, I>::get())
+			{
+				ensure!(
+					update.header.slot.saturating_add(T::WeakSubjectivityPeriod::get()) >=
+						latest_finalized_state.slot,
+					Error::<T, I>::StaleCheckpoint
+				);
+			}
+
+			ensure!(
+				update.current_sync_committee.pubkeys.len() == T::BeaconSpec::SYNC_COMMITTEE_SIZE,
+				Error::<T, I>::InvalidSyncCommitteeSize
+			);
 			let sync_committee_root = update
 				.current_sync_committee
 				.hash_tree_root()
-				.map_err(|_| Error::<T>::SyncCommitteeHashTreeRootFailed)?;
+				.map_err(|_| Error::<T, I>::SyncCommitteeHashTreeRootFailed)?;
 
 			// Verifies the sync committee in the Beacon state.
+			let sync_committee_gindex =
+				Self::current_sync_committee_gindex_at_slot(update.header.slot);
 			ensure!(
 				verify_merkle_branch(
 					sync_committee_root,
 					&update.current_sync_committee_branch,
-					config::CURRENT_SYNC_COMMITTEE_SUBTREE_INDEX,
-					config::CURRENT_SYNC_COMMITTEE_DEPTH,
+					subtree_index(sync_committee_gindex),
+					generalized_index_length(sync_committee_gindex),
 					update.header.state_root
 				),
-				Error::<T>::InvalidSyncCommitteeMerkleProof
+				Error::<T, I>::InvalidSyncCommitteeMerkleProof
 			);
 
 			let header_root: H256 = update
 				.header
 				.hash_tree_root()
-				.map_err(|_| Error::<T>::HeaderHashTreeRootFailed)?;
+				.map_err(|_| Error::<T, I>::HeaderHashTreeRootFailed)?;
 
 			// This is used for ancestry proofs in ExecutionHeader updates. This verifies the
 			// BeaconState: the beacon state root is the tree root; the `block_roots` hash is the
 			// tree leaf.
+			let block_roots_gindex = Self::block_roots_gindex_at_slot(update.header.slot);
 			ensure!(
 				verify_merkle_branch(
 					update.block_roots_root,
 					&update.block_roots_branch,
-					config::BLOCK_ROOTS_SUBTREE_INDEX,
-					config::BLOCK_ROOTS_DEPTH,
+					subtree_index(block_roots_gindex),
+					generalized_index_length(block_roots_gindex),
 					update.header.state_root
 				),
-				Error::<T>::InvalidBlockRootsRootMerkleProof
+				Error::<T, I>::InvalidBlockRootsRootMerkleProof
 			);
 
+			// Verifies the next sync committee, if present, in the Beacon state.
+			let next_sync_committee_prepared = match &update.next_sync_committee_update {
+				Some(next_sync_committee_update) => {
+					ensure!(
+						next_sync_committee_update.next_sync_committee.pubkeys.len() ==
+							T::BeaconSpec::SYNC_COMMITTEE_SIZE,
+						Error::<T, I>::InvalidSyncCommitteeSize
+					);
+					let next_sync_committee_root = next_sync_committee_update
+						.next_sync_committee
+						.hash_tree_root()
+						.map_err(|_| Error::<T, I>::SyncCommitteeHashTreeRootFailed)?;
+					let next_sync_committee_gindex =
+						Self::next_sync_committee_gindex_at_slot(update.header.slot);
+					ensure!(
+						verify_merkle_branch(
+							next_sync_committee_root,
+							&next_sync_committee_update.next_sync_committee_branch,
+							subtree_index(next_sync_committee_gindex),
+							generalized_index_length(next_sync_committee_gindex),
+							update.header.state_root
+						),
+						Error::<T, I>::InvalidSyncCommitteeMerkleProof
+					);
+					let prepared: SyncCommitteePrepared = (&next_sync_committee_update
+						.next_sync_committee)
+						.try_into()
+						.map_err(|_| <Error<T, I>>::BLSPreparePublicKeysFailed)?;
+					Some(prepared)
+				},
+				None => None,
+			};
+
 			let sync_committee_prepared: SyncCommitteePrepared = (&update.current_sync_committee)
 				.try_into()
-				.map_err(|_| <Error<T>>::BLSPreparePublicKeysFailed)?;
-			<CurrentSyncCommittee<T>>::set(sync_committee_prepared);
-			<NextSyncCommittee<T>>::kill();
-			InitialCheckpointRoot::<T>::set(header_root);
-			<LatestExecutionState<T>>::kill();
+				.map_err(|_| <Error<T, I>>::BLSPreparePublicKeysFailed)?;
+			<CurrentSyncCommittee<T, I>>::set(sync_committee_prepared);
+			match next_sync_committee_prepared {
+				Some(prepared) => <NextSyncCommittee<T, I>>::set(prepared),
+				None => <NextSyncCommittee<T, I>>::kill(),
+			}
+			InitialCheckpointRoot::<T, I>::set(header_root);
+			<LatestExecutionState<T, I>>::kill();
+			<BestValidUpdate<T, I>>::kill();
 
 			Self::store_validators_root(update.validators_root);
-			Self::store_finalized_header(header_root, update.header, update.block_roots_root)?;
+			Self::store_finalized_header(
+				header_root,
+				update.header,
+				update.block_roots_root,
+				false,
+			)?;
 
 			Ok(())
 		}
 
-		pub(crate) fn process_update(update: &Update) -> DispatchResult {
-			Self::cross_check_execution_state()?;
+		/// References https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#process_light_client_update
+		/// Verifies a beacon header update and keeps it if it is the best valid update so far.
+		/// Updates proving finality are applied right away, while the best valid update is applied
+		/// once the update timeout has passed. Returns whether the update advanced the light
+		/// client, by importing a newer finalized header or by setting or rotating the sync
+		/// committee, or halted it on conflicting finality.
+		pub(crate) fn process_update(update: &Update) -> Result<bool, DispatchError> {
 			Self::verify_update(update)?;
-			Self::apply_update(update)?;
-			Ok(())
+
+			// Keeps the update if it is the best valid update, counting the update timeout from the
+			// block in which the first best valid update was kept.
+			let kept_since = match <BestValidUpdate<T, I>>::get() {
+				Some((best_valid_update, since)) =>
+					Self::is_better_update(update, &best_valid_update).then_some(since),
+				None => Some(<frame_system::Pallet<T>>::block_number()),
+			};
+			if let Some(since) = kept_since {
+				<BestValidUpdate<T, I>>::set(Some((update.clone(), since)));
+			}
+
+			let mut advanced = false;
+			if update.is_finality_update() {
+				advanced = Self::apply_update(update, false)?;
+				if Self::is_halted() {
+					return Ok(advanced)
+				}
+				// The best valid update is superseded once the light client advances.
+				if advanced {
+					<BestValidUpdate<T, I>>::kill();
+				}
+			}
+
+			// A best valid update that can no longer be applied, e.g. because the light client
+			// has moved to another sync committee period since it was kept, is dropped without
+			// failing the update being processed.
+			let forced = match with_storage_layer(Self::process_force_update) {
+				Ok(forced) => forced,
+				Err(err) => {
+					log::warn!(
+						target: "ethereum-beacon-client",
+						"💫 Dropped best valid update which cannot be applied: {:?}.",
+						err
+					);
+					<BestValidUpdate<T, I>>::kill();
+					false
+				},
+			};
+
+			Ok(forced || advanced)
 		}
 
-		/// Cross check to make sure that execution header import does not fall too far behind
-		/// finalised beacon header import. If that happens just return an error and pause
-		/// processing until execution header processing has caught up.
-		fn cross_check_execution_state() -> DispatchResult {
+		/// References https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#process_light_client_store_force_update
+		/// Applies the best valid update if no newer header has been finalized for `UpdateTimeout`
+		/// blocks since it was first kept. The attested header of the update is imported as
+		/// finalized in place of a finalized header that is not newer. Returns whether the light
+		/// client was advanced.
+		///
+		/// The attested header is only signed by the sync committee, not finalized by the beacon
+		/// chain, so it keeps the light client following sync committee rotations but is not
+		/// recorded in `FinalizedBlockRoots`. It is therefore neither checked for conflicting
+		/// finality, nor accepted to prove execution headers, and so messages, until a header is
+		/// finalized again.
+		pub(crate) fn process_force_update() -> Result<bool, DispatchError> {
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut update = match <BestValidUpdate<T, I>>::get() {
+				Some((update, since)) if now > since.saturating_add(T::UpdateTimeout::get()) =>
+					update,
+				_ => return Ok(false),
+			};
+			<BestValidUpdate<T, I>>::kill();
+
 			let latest_finalized_state =
-				FinalizedBeaconState::<T>::get(LatestFinalizedBlockRoot::<T>::get())
-					.ok_or(Error::<T>::NotBootstrapped)?;
-			let latest_execution_state = Self::latest_execution_state();
-			// The execution header import should be at least within the slot range of a sync
-			// committee period.
-			let max_latency = config::EPOCHS_PER_SYNC_COMMITTEE_PERIOD * config::SLOTS_PER_EPOCH;
-			ensure!(
-				latest_execution_state.beacon_slot == 0 ||
-					latest_finalized_state.slot <
-						latest_execution_state.beacon_slot + max_latency as u64,
-				Error::<T>::ExecutionHeaderTooFarBehind
+				FinalizedBeaconState::<T, I>::get(LatestFinalizedBlockRoot::<T, I>::get())
+					.ok_or(Error::<T, I>::NotBootstrapped)?;
+
+			let forced = update.finalized_header.slot <= latest_finalized_state.slot;
+			if forced {
+				// Only updates without finality may prove the `block_roots` of the attested header.
+				if update.is_finality_update() {
+					update.block_roots_root = H256::zero();
+				}
+				update.finalized_header = update.attested_header;
+			}
+
+			log::warn!(
+				target: "ethereum-beacon-client",
+				"💫 No finality since slot {}. Forcing update to slot {}.",
+				latest_finalized_state.slot,
+				update.finalized_header.slot
 			);
-			Ok(())
+			Self::deposit_event(Event::UpdateForced { slot: update.finalized_header.slot });
+
+			Self::apply_update(&update, forced)
+		}
+
+		/// References https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#is_better_update
+		/// Returns whether `new_update` is better than `old_update`. Both updates are valid, so
+		/// both have a supermajority of sync committee participants.
+		pub(crate) fn is_better_update(new_update: &Update, old_update: &Update) -> bool {
+			let period = compute_period::<T::BeaconSpec>;
+
+			// Compare presence of relevant sync committee
+			let has_relevant_sync_committee = |update: &Update| {
+				update.next_sync_committee_update.is_some() &&
+					period(update.attested_header.slot) == period(update.signature_slot)
+			};
+			let new_has_relevant_sync_committee = has_relevant_sync_committee(new_update);
+			if new_has_relevant_sync_committee != has_relevant_sync_committee(old_update) {
+				return new_has_relevant_sync_committee
+			}
+
+			// Compare indication of any finality
+			let new_has_finality = new_update.is_finality_update();
+			if new_has_finality != old_update.is_finality_update() {
+				return new_has_finality
+			}
+
+			// Compare sync committee finality
+			if new_has_finality {
+				let has_sync_committee_finality = |update: &Update| {
+					period(update.finalized_header.slot) == period(update.attested_header.slot)
+				};
+				let new_has_sync_committee_finality = has_sync_committee_finality(new_update);
+				if new_has_sync_committee_finality != has_sync_committee_finality(old_update) {
+					return new_has_sync_committee_finality
+				}
+			}
+
+			// Tiebreaker 1: Sync committee participation beyond supermajority
+			let participants = |update: &Update| {
+				sync_committee_sum(&decompress_sync_committee_bits(
+					&update.sync_aggregate.sync_committee_bits,
+				))
+			};
+			let new_participants = participants(new_update);
+			let old_participants = participants(old_update);
+			if new_participants != old_participants {
+				return new_participants > old_participants
+			}
+
+			// Tiebreaker 2: Prefer older data (fewer changes to best)
+			if new_update.attested_header.slot != old_update.attested_header.slot {
+				return new_update.attested_header.slot < old_update.attested_header.slot
+			}
+			new_update.signature_slot < old_update.signature_slot
 		}
 
 		/// References and strictly follows https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#validate_light_client_update
 		/// Verifies that provided next sync committee is valid through a series of checks
 		/// (including checking that a sync committee period isn't skipped and that the header is
 		/// signed by the current sync committee.
-		fn verify_update(update: &Update) -> DispatchResult {
+		pub(crate) fn verify_update(update: &Update) -> DispatchResult {
+			// Verify the sync committee bits cover the whole sync committee of the preset.
+			ensure!(
+				update.sync_aggregate.sync_committee_bits.len() ==
+					T::BeaconSpec::SYNC_COMMITTEE_BITS_SIZE,
+				Error::<T, I>::InvalidSyncCommitteeSize
+			);
+
 			// Verify sync committee has sufficient participants.
 			let participation =
-				decompress_sync_committee_bits(update.sync_aggregate.sync_committee_bits);
+				decompress_sync_committee_bits(&update.sync_aggregate.sync_committee_bits);
 			Self::sync_committee_participation_is_supermajority(&participation)?;
 
 			// Verify update does not skip a sync committee period.
 			ensure!(
 				update.signature_slot > update.attested_header.slot &&
 					update.attested_header.slot >= update.finalized_header.slot,
-				Error::<T>::InvalidUpdateSlot
+				Error::<T, I>::InvalidUpdateSlot
 			);
 
 			// Retrieve latest finalized state.
 			let latest_finalized_state =
-				FinalizedBeaconState::<T>::get(LatestFinalizedBlockRoot::<T>::get())
-					.ok_or(Error::<T>::NotBootstrapped)?;
+				FinalizedBeaconState::<T, I>::get(LatestFinalizedBlockRoot::<T, I>::get())
+					.ok_or(Error::<T, I>::NotBootstrapped)?;
 
-			let store_period = compute_period(latest_finalized_state.slot);
-			let signature_period = compute_period(update.signature_slot);
-			if <NextSyncCommittee<T>>::exists() {
+			let store_period = compute_period::<T::BeaconSpec>(latest_finalized_state.slot);
+			let signature_period = compute_period::<T::BeaconSpec>(update.signature_slot);
+			if <NextSyncCommittee<T, I>>::exists() {
 				ensure!(
 					(store_period..=store_period + 1).contains(&signature_period),
-					Error::<T>::SkippedSyncCommitteePeriod
+					Error::<T, I>::SkippedSyncCommitteePeriod
 				)
 			} else {
-				ensure!(signature_period == store_period, Error::<T>::SkippedSyncCommitteePeriod)
+				ensure!(signature_period == store_period, Error::<T, I>::SkippedSyncCommitteePeriod)
 			}
 
 			// Verify update is relevant.
-			let update_attested_period = compute_period(update.attested_header.slot);
-			let update_has_next_sync_committee = !<NextSyncCommittee<T>>::exists() &&
+			let update_attested_period =
+				compute_period::<T::BeaconSpec>(update.attested_header.slot);
+			let update_has_next_sync_committee = !<NextSyncCommittee<T, I>>::exists() &&
 				(update.next_sync_committee_update.is_some() &&
 					update_attested_period == store_period);
 			ensure!(
 				update.attested_header.slot > latest_finalized_state.slot ||
 					update_has_next_sync_committee,
-				Error::<T>::NotRelevant
+				Error::<T, I>::NotRelevant
 			);
 
-			// Verify that the `finality_branch`, if present, confirms `finalized_header` to match
-			// the finalized checkpoint root saved in the state of `attested_header`.
-			let finalized_block_root: H256 = update
-				.finalized_header
-				.hash_tree_root()
-				.map_err(|_| Error::<T>::HeaderHashTreeRootFailed)?;
-			ensure!(
-				verify_merkle_branch(
-					finalized_block_root,
-					&update.finality_branch,
-					config::FINALIZED_ROOT_SUBTREE_INDEX,
-					config::FINALIZED_ROOT_DEPTH,
-					update.attested_header.state_root
-				),
-				Error::<T>::InvalidHeaderMerkleProof
-			);
+			if update.is_finality_update() {
+				// Verify that the `finality_branch` confirms `finalized_header` to match the
+				// finalized checkpoint root saved in the state of `attested_header`.
+				let finalized_block_root: H256 = update
+					.finalized_header
+					.hash_tree_root()
+					.map_err(|_| Error::<T, I>::HeaderHashTreeRootFailed)?;
+				let finalized_root_gindex =
+					Self::finalized_root_gindex_at_slot(update.attested_header.slot);
+				ensure!(
+					verify_merkle_branch(
+						finalized_block_root,
+						&update.finality_branch,
+						subtree_index(finalized_root_gindex),
+						generalized_index_length(finalized_root_gindex),
+						update.attested_header.state_root
+					),
+					Error::<T, I>::InvalidHeaderMerkleProof
+				);
 
-			// Though following check does not belong to ALC spec we verify block_roots_root to
-			// match the finalized checkpoint root saved in the state of `finalized_header` so to
-			// cache it for later use in `verify_ancestry_proof`.
-			ensure!(
-				verify_merkle_branch(
-					update.block_roots_root,
-					&update.block_roots_branch,
-					config::BLOCK_ROOTS_SUBTREE_INDEX,
-					config::BLOCK_ROOTS_DEPTH,
-					update.finalized_header.state_root
-				),
-				Error::<T>::InvalidBlockRootsRootMerkleProof
-			);
+				// Though following check does not belong to ALC spec we verify block_roots_root to
+				// match the finalized checkpoint root saved in the state of `finalized_header` so
+				// to cache it for later use in `verify_ancestry_proof`.
+				let block_roots_gindex =
+					Self::block_roots_gindex_at_slot(update.finalized_header.slot);
+				ensure!(
+					verify_merkle_branch(
+						update.block_roots_root,
+						&update.block_roots_branch,
+						subtree_index(block_roots_gindex),
+						generalized_index_length(block_roots_gindex),
+						update.finalized_header.state_root
+					),
+					Error::<T, I>::InvalidBlockRootsRootMerkleProof
+				);
+			} else {
+				// Without finality, the `finality_branch` must be empty.
+				ensure!(
+					update.finality_branch.iter().all(|node| node.is_zero()),
+					Error::<T, I>::InvalidHeaderMerkleProof
+				);
+
+				// The `block_roots_root` of the `attested_header` may be proven instead, for
+				// ancestry proofs against the attested header if the update is forced.
+				let block_roots_gindex =
+					Self::block_roots_gindex_at_slot(update.attested_header.slot);
+				ensure!(
+					(update.block_roots_branch.is_empty() && update.block_roots_root.is_zero()) ||
+						verify_merkle_branch(
+							update.block_roots_root,
+							&update.block_roots_branch,
+							subtree_index(block_roots_gindex),
+							generalized_index_length(block_roots_gindex),
+							update.attested_header.state_root
+						),
+					Error::<T, I>::InvalidBlockRootsRootMerkleProof
+				);
+			}
 
 			// Verify that the `next_sync_committee`, if present, actually is the next sync
 			// committee saved in the state of the `attested_header`.
 			if let Some(next_sync_committee_update) = &update.next_sync_committee_update {
+				ensure!(
+					next_sync_committee_update.next_sync_committee.pubkeys.len() ==
+						T::BeaconSpec::SYNC_COMMITTEE_SIZE,
+					Error::<T, I>::InvalidSyncCommitteeSize
+				);
 				let sync_committee_root = next_sync_committee_update
 					.next_sync_committee
 					.hash_tree_root()
-					.map_err(|_| Error::<T>::SyncCommitteeHashTreeRootFailed)?;
-				if update_attested_period == store_period && <NextSyncCommittee<T>>::exists() {
-					let next_committee_root = <NextSyncCommittee<T>>::get().root;
+					.map_err(|_| Error::<T, I>::SyncCommitteeHashTreeRootFailed)?;
+				if update_attested_period == store_period && <NextSyncCommittee<T, I>>::exists() {
+					let next_committee_root = <NextSyncCommittee<T, I>>::get().root;
 					ensure!(
 						sync_committee_root == next_committee_root,
-						Error::<T>::InvalidSyncCommitteeUpdate
+						Error::<T, I>::InvalidSyncCommitteeUpdate
 					);
 				}
+				let next_sync_committee_gindex =
+					Self::next_sync_committee_gindex_at_slot(update.attested_header.slot);
 				ensure!(
 					verify_merkle_branch(
 						sync_committee_root,
 						&next_sync_committee_update.next_sync_committee_branch,
-						config::NEXT_SYNC_COMMITTEE_SUBTREE_INDEX,
-						config::NEXT_SYNC_COMMITTEE_DEPTH,
+						subtree_index(next_sync_committee_gindex),
+						generalized_index_length(next_sync_committee_gindex),
 						update.attested_header.state_root
 					),
-					Error::<T>::InvalidSyncCommitteeMerkleProof
+					Error::<T, I>::InvalidSyncCommitteeMerkleProof
 				);
 			}
 
 			// Verify sync committee aggregate signature.
 			let sync_committee = if signature_period == store_period {
-				<CurrentSyncCommittee<T>>::get()
+				<CurrentSyncCommittee<T, I>>::get()
 			} else {
-				<NextSyncCommittee<T>>::get()
+				<NextSyncCommittee<T, I>>::get()
 			};
-			let absent_pubkeys =
-				Self::find_pubkeys(&participation, (*sync_committee.pubkeys).as_ref(), false);
+			// Only the public keys of absent participants are decompressed, natively when
+			// verifying through the host function.
+			let absent_pubkeys = Self::find_pubkeys(&participation, &sync_committee.pubkeys, false);
+			#[cfg(not(feature = "bls-host-function"))]
+			let absent_pubkeys = prepare_g1_pubkeys(&absent_pubkeys)
+				.map_err(|_| Error::<T, I>::BLSPreparePublicKeysFailed)?;
 			let signing_root = Self::signing_root(
 				&update.attested_header,
 				Self::validators_root(),
@@ -427,7 +891,7 @@ pub mod pallet {
 				signing_root,
 				&update.sync_aggregate.sync_committee_signature,
 			)
-			.map_err(|e| Error::<T>::BLSVerificationFailed(e))?;
+			.map_err(|e| Error::<T, I>::BLSVerificationFailed(e))?;
 
 			Ok(())
 		}
@@ -435,28 +899,53 @@ pub mod pallet {
 		/// Reference and strictly follows https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md#apply_light_client_update
 		/// Applies a finalized beacon header update to the beacon client. If a next sync committee
 		/// is present in the update, verify the sync committee by converting it to a
-		/// SyncCommitteePrepared type. Stores the provided finalized header.
-		fn apply_update(update: &Update) -> DispatchResult {
+		/// SyncCommitteePrepared type. Stores the provided finalized header, which is `forced` if
+		/// it was not finalized by the beacon chain. Returns whether the light client state was
+		/// advanced. Halts the light client instead if the finalized header conflicts with one
+		/// already finalized at the same slot.
+		fn apply_update(update: &Update, forced: bool) -> Result<bool, DispatchError> {
 			let latest_finalized_state =
-				FinalizedBeaconState::<T>::get(LatestFinalizedBlockRoot::<T>::get())
-					.ok_or(Error::<T>::NotBootstrapped)?;
+				FinalizedBeaconState::<T, I>::get(LatestFinalizedBlockRoot::<T, I>::get())
+					.ok_or(Error::<T, I>::NotBootstrapped)?;
+
+			// A different header finalized at an already finalized slot is evidence of
+			// conflicting finality. A forced header is not finalized, so it cannot conflict.
+			let finalized_block_root: H256 = update
+				.finalized_header
+				.hash_tree_root()
+				.map_err(|_| Error::<T, I>::HeaderHashTreeRootFailed)?;
+			if !forced {
+				if let Some(existing_root) =
+					<FinalizedBlockRootBuffer<T, I>>::get(update.finalized_header.slot)
+				{
+					if existing_root != finalized_block_root {
+						Self::halt_on_equivocation(update, existing_root, finalized_block_root);
+						return Ok(true)
+					}
+				}
+			}
+
+			let mut advanced = false;
 			if let Some(next_sync_committee_update) = &update.next_sync_committee_update {
-				let store_period = compute_period(latest_finalized_state.slot);
-				let update_finalized_period = compute_period(update.finalized_header.slot);
+				let store_period = compute_period::<T::BeaconSpec>(latest_finalized_state.slot);
+				let update_finalized_period =
+					compute_period::<T::BeaconSpec>(update.finalized_header.slot);
 				let sync_committee_prepared: SyncCommitteePrepared = (&next_sync_committee_update
 					.next_sync_committee)
 					.try_into()
-					.map_err(|_| <Error<T>>::BLSPreparePublicKeysFailed)?;
+					.map_err(|_| <Error<T, I>>::BLSPreparePublicKeysFailed)?;
 
-				if !<NextSyncCommittee<T>>::exists() {
+				if !<NextSyncCommittee<T, I>>::exists() {
 					ensure!(
 						update_finalized_period == store_period,
-						<Error<T>>::InvalidSyncCommitteeUpdate
+						<Error<T, I>>::InvalidSyncCommitteeUpdate
 					);
-					<NextSyncCommittee<T>>::set(sync_committee_prepared);
+					<NextSyncCommittee<T, I>>::set(sync_committee_prepared);
+					advanced = true;
 				} else if update_finalized_period == store_period + 1 {
-					<CurrentSyncCommittee<T>>::set(<NextSyncCommittee<T>>::get());
-					<NextSyncCommittee<T>>::set(sync_committee_prepared);
+					<CurrentSyncCommittee<T, I>>::set(<NextSyncCommittee<T, I>>::get());
+					<NextSyncCommittee<T, I>>::set(sync_committee_prepared);
+					advanced = true;
 				}
 				log::info!(
 					target: "ethereum-beacon-client",
@@ -469,18 +958,44 @@ pub mod pallet {
 			};
 
 			if update.finalized_header.slot > latest_finalized_state.slot {
-				let finalized_block_root: H256 = update
-					.finalized_header
-					.hash_tree_root()
-					.map_err(|_| Error::<T>::HeaderHashTreeRootFailed)?;
 				Self::store_finalized_header(
 					finalized_block_root,
 					update.finalized_header,
 					update.block_roots_root,
+					forced,
 				)?;
+				advanced = true;
 			}
 
-			Ok(())
+			Ok(advanced)
+		}
+
+		/// Returns the weight of submitting the given update, which depends on whether it carries
+		/// the next sync committee.
+		pub(super) fn update_weight(update: &Update) -> Weight {
+			let weight = match update.next_sync_committee_update {
+				None => T::WeightInfo::submit(),
+				Some(_) => T::WeightInfo::submit_with_sync_committee(),
+			};
+			// Any update may apply the best valid update once the update timeout has passed.
+			weight.saturating_add(T::WeightInfo::force_update())
+		}
+
+		/// Consumes one of the fee-free updates allowed per block. Returns `Pays::Yes` once
+		/// `MaxFreeUpdatesPerBlock` updates have been accepted free of charge in this block.
+		pub(crate) fn consume_free_update() -> Pays {
+			let now = <frame_system::Pallet<T>>::block_number();
+			<FreeUpdatesInBlock<T, I>>::mutate(|(block_number, count)| {
+				if *block_number != now {
+					*block_number = now;
+					*count = 0;
+				}
+				if *count >= T::MaxFreeUpdatesPerBlock::get() {
+					return Pays::Yes
+				}
+				*count = count.saturating_add(1);
+				Pays::No
+			})
 		}
 
 		/// Validates an execution header for import. The beacon header containing the execution
@@ -489,22 +1004,79 @@ pub mod pallet {
 		pub(crate) fn process_execution_header_update(
 			update: &ExecutionHeaderUpdate,
 		) -> DispatchResult {
+			// Execution headers are imported sparsely, each one proven against a finalized header,
+			// so only headers that have not been imported yet are accepted.
+			ensure!(
+				!<ExecutionHeaders<T, I>>::contains_key(update.execution_header.block_hash()),
+				Error::<T, I>::ExecutionHeaderAlreadyImported
+			);
+
+			Self::verify_execution_header_parent(&update.execution_header)?;
+
+			let block_root = Self::verify_execution_header_update(update)?;
+
+			let mut header: CompactExecutionHeader = update.execution_header.clone().into();
+			header.transactions_root = Some(update.execution_block_roots.transactions_root);
+
+			Self::store_execution_header(
+				update.execution_header.block_hash(),
+				header,
+				update.header.slot,
+				block_root,
+			);
+
+			Ok(())
+		}
+
+		/// Verifies that the execution header extends the chain of imported execution headers.
+		/// A header that directly follows the latest imported header must be its child, and a
+		/// header whose parent has been imported must directly follow it.
+		fn verify_execution_header_parent(
+			execution_header: &VersionedExecutionPayloadHeader,
+		) -> DispatchResult {
+			let block_number = execution_header.block_number();
+			let parent_hash = execution_header.parent_hash();
+
+			let latest = LatestExecutionState::<T, I>::get();
+			if latest.block_hash != H256::zero() && block_number == latest.block_number + 1 {
+				ensure!(
+					parent_hash == latest.block_hash,
+					Error::<T, I>::InvalidExecutionHeaderParent
+				);
+			}
+
+			if let Some(parent) = <ExecutionHeaderBuffer<T, I>>::get(parent_hash) {
+				ensure!(
+					parent.block_number + 1 == block_number,
+					Error::<T, I>::InvalidExecutionHeaderParent
+				);
+			}
+
+			Ok(())
+		}
+
+		/// Verifies that the execution header is rooted in the beacon header body, and that the
+		/// beacon header is finalized. Returns the block root of the beacon header.
+		pub(crate) fn verify_execution_header_update(
+			update: &ExecutionHeaderUpdate,
+		) -> Result<H256, DispatchError> {
 			let latest_finalized_state =
-				FinalizedBeaconState::<T>::get(LatestFinalizedBlockRoot::<T>::get())
-					.ok_or(Error::<T>::NotBootstrapped)?;
+				FinalizedBeaconState::<T, I>::get(LatestFinalizedBlockRoot::<T, I>::get())
+					.ok_or(Error::<T, I>::NotBootstrapped)?;
 			// Checks that the header is an ancestor of a finalized header, using slot number.
 			ensure!(
 				update.header.slot <= latest_finalized_state.slot,
-				Error::<T>::HeaderNotFinalized
+				Error::<T, I>::HeaderNotFinalized
 			);
 
-			// Checks that we don't skip execution headers, they need to be imported sequentially.
-			let latest_execution_state: ExecutionHeaderState = Self::latest_execution_state();
+			// Checks that the execution header version matches the fork active at the beacon
+			// header's slot, since the SSZ container (and so its hash tree root) differs per fork.
 			ensure!(
-				latest_execution_state.block_number == 0 ||
-					update.execution_header.block_number ==
-						latest_execution_state.block_number + 1,
-				Error::<T>::ExecutionHeaderSkippedSlot
+				Self::execution_header_version_matches(
+					&update.execution_header,
+					update.header.slot
+				),
+				Error::<T, I>::InvalidExecutionHeaderVersion
 			);
 
 			// Gets the hash tree root of the execution header, in preparation for the execution
@@ -513,53 +1085,73 @@ pub mod pallet {
 			let execution_header_root: H256 = update
 				.execution_header
 				.hash_tree_root()
-				.map_err(|_| Error::<T>::BlockBodyHashTreeRootFailed)?;
+				.map_err(|_| Error::<T, I>::BlockBodyHashTreeRootFailed)?;
 
+			let execution_header_gindex = Self::execution_header_gindex_at_slot(update.header.slot);
 			ensure!(
 				verify_merkle_branch(
 					execution_header_root,
 					&update.execution_branch,
-					config::EXECUTION_HEADER_SUBTREE_INDEX,
-					config::EXECUTION_HEADER_DEPTH,
+					subtree_index(execution_header_gindex),
+					generalized_index_length(execution_header_gindex),
 					update.header.body_root
 				),
-				Error::<T>::InvalidExecutionHeaderProof
+				Error::<T, I>::InvalidExecutionHeaderProof
 			);
 
 			let block_root: H256 = update
 				.header
 				.hash_tree_root()
-				.map_err(|_| Error::<T>::HeaderHashTreeRootFailed)?;
+				.map_err(|_| Error::<T, I>::HeaderHashTreeRootFailed)?;
 
 			match &update.ancestry_proof {
-				Some(proof) => {
+				Some(AncestryProof::BlockRoots { header_branch, finalized_block_root }) => {
 					Self::verify_ancestry_proof(
 						block_root,
 						update.header.slot,
-						&proof.header_branch,
-						proof.finalized_block_root,
+						header_branch,
+						*finalized_block_root,
+					)?;
+				},
+				Some(AncestryProof::HistoricalSummaries {
+					header_branch,
+					block_summary_root,
+					block_summary_root_branch,
+					finalized_header,
+				}) => {
+					Self::verify_historical_summaries_ancestry_proof(
+						block_root,
+						update.header.slot,
+						header_branch,
+						*block_summary_root,
+						block_summary_root_branch,
+						finalized_header,
 					)?;
 				},
 				None => {
 					// If the ancestry proof is not provided, we expect this header to be a
 					// finalized header. We need to check that the header hash matches the finalized
 					// header root at the expected slot.
-					let state = <FinalizedBeaconState<T>>::get(block_root)
-						.ok_or(Error::<T>::ExpectedFinalizedHeaderNotStored)?;
+					let state = <FinalizedBeaconState<T, I>>::get(block_root)
+						.ok_or(Error::<T, I>::ExpectedFinalizedHeaderNotStored)?;
 					if update.header.slot != state.slot {
-						return Err(Error::<T>::ExpectedFinalizedHeaderNotStored.into())
+						return Err(Error::<T, I>::ExpectedFinalizedHeaderNotStored.into())
 					}
+					Self::ensure_not_forced(block_root, state.slot)?;
 				},
 			}
 
-			Self::store_execution_header(
-				update.execution_header.block_hash,
-				update.execution_header.clone().into(),
-				update.header.slot,
-				block_root,
+			// Recomputes the execution block hash from the execution block header, so that the
+			// block hash, and the roots retained with the header, are bound to the proven payload.
+			let execution_header = update
+				.execution_header
+				.execution_header(&update.execution_block_roots, update.header.parent_root);
+			ensure!(
+				execution_header.hash() == update.execution_header.block_hash(),
+				Error::<T, I>::InvalidExecutionBlockHash
 			);
 
-			Ok(())
+			Ok(block_root)
 		}
 
 		/// Verify that `block_root` is an ancestor of `finalized_block_root` Used to prove that
@@ -571,28 +1163,123 @@ pub mod pallet {
 			block_root_proof: &[H256],
 			finalized_block_root: H256,
 		) -> DispatchResult {
-			let state = <FinalizedBeaconState<T>>::get(finalized_block_root)
-				.ok_or(Error::<T>::ExpectedFinalizedHeaderNotStored)?;
+			let state = <FinalizedBeaconState<T, I>>::get(finalized_block_root)
+				.ok_or(Error::<T, I>::ExpectedFinalizedHeaderNotStored)?;
+			Self::ensure_not_forced(finalized_block_root, state.slot)?;
 
-			ensure!(block_slot < state.slot, Error::<T>::HeaderNotFinalized);
-
-			let index_in_array = block_slot % (SLOTS_PER_HISTORICAL_ROOT as u64);
-			let leaf_index = (SLOTS_PER_HISTORICAL_ROOT as u64) + index_in_array;
+			ensure!(block_slot < state.slot, Error::<T, I>::HeaderNotFinalized);
 
 			ensure!(
-				verify_merkle_branch(
+				Self::verify_block_root_in_block_roots(
 					block_root,
-					&block_root_proof,
-					leaf_index as usize,
-					config::BLOCK_ROOT_AT_INDEX_DEPTH,
+					block_slot,
+					block_root_proof,
 					state.block_roots_root
 				),
-				Error::<T>::InvalidAncestryMerkleProof
+				Error::<T, I>::InvalidAncestryMerkleProof
 			);
 
 			Ok(())
 		}
 
+		/// Verify that `block_root` is an ancestor of `finalized_header`, through the historical
+		/// summary of its `block_roots` accumulated in the finalized beacon state since Capella.
+		/// Used for execution headers older than the `block_roots` of any stored finalized state.
+		fn verify_historical_summaries_ancestry_proof(
+			block_root: H256,
+			block_slot: u64,
+			block_root_proof: &[H256],
+			block_summary_root: H256,
+			block_summary_root_proof: &[H256],
+			finalized_header: &BeaconHeader,
+		) -> DispatchResult {
+			let finalized_block_root: H256 = finalized_header
+				.hash_tree_root()
+				.map_err(|_| Error::<T, I>::HeaderHashTreeRootFailed)?;
+			ensure!(
+				<FinalizedBeaconState<T, I>>::contains_key(finalized_block_root),
+				Error::<T, I>::ExpectedFinalizedHeaderNotStored
+			);
+			Self::ensure_not_forced(finalized_block_root, finalized_header.slot)?;
+
+			// A historical summary is appended to the beacon state at the end of each
+			// `SLOTS_PER_HISTORICAL_ROOT` slots period, starting with the period of the Capella
+			// fork.
+			let slots_per_historical_root = T::BeaconSpec::SLOTS_PER_HISTORICAL_ROOT as u64;
+			let capella_slot = <ForkSchedule<T, I>>::get()
+				.capella
+				.epoch
+				.saturating_mul(T::BeaconSpec::SLOTS_PER_EPOCH as u64);
+			let block_period = block_slot / slots_per_historical_root;
+			let capella_period = capella_slot / slots_per_historical_root;
+			ensure!(
+				block_period >= capella_period &&
+					block_period < finalized_header.slot / slots_per_historical_root,
+				Error::<T, I>::HistoricalSummaryNotAvailable
+			);
+			let summary_index = block_period - capella_period;
+			ensure!(
+				summary_index < 1 << config::HISTORICAL_ROOTS_LIMIT_DEPTH,
+				Error::<T, I>::HistoricalSummaryNotAvailable
+			);
+
+			ensure!(
+				Self::verify_block_root_in_block_roots(
+					block_root,
+					block_slot,
+					block_root_proof,
+					block_summary_root
+				),
+				Error::<T, I>::InvalidAncestryMerkleProof
+			);
+
+			// The `block_summary_root` is proven from the finalized state root through the
+			// `historical_summaries` field, the list data root (the left child of the list root,
+			// next to its length), the historical summary at `summary_index` and its first field.
+			let historical_summaries_gindex =
+				Self::historical_summaries_gindex_at_slot(finalized_header.slot);
+			let depth = generalized_index_length(historical_summaries_gindex) +
+				config::HISTORICAL_ROOTS_LIMIT_DEPTH +
+				2;
+			let list_data_index = subtree_index(historical_summaries_gindex) << 1;
+			let summary_position =
+				(list_data_index << config::HISTORICAL_ROOTS_LIMIT_DEPTH) | summary_index as usize;
+			let index = summary_position << 1;
+			ensure!(
+				verify_merkle_branch(
+					block_summary_root,
+					block_summary_root_proof,
+					index,
+					depth,
+					finalized_header.state_root
+				),
+				Error::<T, I>::InvalidAncestryMerkleProof
+			);
+
+			Ok(())
+		}
+
+		/// Verify that `block_root` is in the `block_roots` vector with root `block_roots_root`, at
+		/// the index for `block_slot`.
+		fn verify_block_root_in_block_roots(
+			block_root: H256,
+			block_slot: u64,
+			block_root_proof: &[H256],
+			block_roots_root: H256,
+		) -> bool {
+			let slots_per_historical_root = T::BeaconSpec::SLOTS_PER_HISTORICAL_ROOT as u64;
+			let index_in_array = block_slot % slots_per_historical_root;
+			let leaf_index = slots_per_historical_root + index_in_array;
+
+			verify_merkle_branch(
+				block_root,
+				block_root_proof,
+				leaf_index as usize,
+				T::BeaconSpec::BLOCK_ROOT_AT_INDEX_DEPTH,
+				block_roots_root,
+			)
+		}
+
 		/// Computes the signing root for a given beacon header and domain. The hash tree root
 		/// of the beacon header is computed, and then the combination of the beacon header hash
 		/// and the domain makes up the signing root.
@@ -602,30 +1289,34 @@ pub mod pallet {
 		) -> Result<H256, DispatchError> {
 			let beacon_header_root = beacon_header
 				.hash_tree_root()
-				.map_err(|_| Error::<T>::HeaderHashTreeRootFailed)?;
+				.map_err(|_| Error::<T, I>::HeaderHashTreeRootFailed)?;
 
 			let hash_root = SigningData { object_root: beacon_header_root, domain }
 				.hash_tree_root()
-				.map_err(|_| Error::<T>::SigningRootHashTreeRootFailed)?;
+				.map_err(|_| Error::<T, I>::SigningRootHashTreeRootFailed)?;
 
 			Ok(hash_root)
 		}
 
 		/// Stores a compacted (slot and block roots root (hash of the `block_roots` beacon state
 		/// field, used for ancestry proof)) beacon state in a ring buffer map, with the header root
-		/// as map key.
+		/// as map key. The header root is also recorded by slot, unless the header was `forced`.
 		fn store_finalized_header(
 			header_root: H256,
 			header: BeaconHeader,
 			block_roots_root: H256,
+			forced: bool,
 		) -> DispatchResult {
 			let slot = header.slot;
 
-			<FinalizedBeaconStateBuffer<T>>::insert(
+			<FinalizedBeaconStateBuffer<T, I>>::insert(
 				header_root,
 				CompactBeaconState { slot: header.slot, block_roots_root },
 			);
-			<LatestFinalizedBlockRoot<T>>::set(header_root);
+			if !forced {
+				<FinalizedBlockRootBuffer<T, I>>::insert(slot, header_root);
+			}
+			<LatestFinalizedBlockRoot<T, I>>::set(header_root);
 
 			log::info!(
 				target: "ethereum-beacon-client",
@@ -639,9 +1330,55 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Ensures that the stored header `block_root` at `slot` was finalized by the beacon chain,
+		/// rather than imported by a forced update.
+		fn ensure_not_forced(block_root: H256, slot: u64) -> DispatchResult {
+			ensure!(
+				<FinalizedBlockRootBuffer<T, I>>::get(slot) == Some(block_root),
+				Error::<T, I>::FinalizedHeaderForced
+			);
+			Ok(())
+		}
+
+		/// Records evidence of two validly signed updates finalizing different headers at the
+		/// same slot and blocks the light client until governance resolves it. Only the first
+		/// evidence is kept, since no further updates are accepted once halted.
+		fn halt_on_equivocation(
+			update: &Update,
+			finalized_block_root: H256,
+			conflicting_block_root: H256,
+		) {
+			let slot = update.finalized_header.slot;
+			if !<Equivocation<T, I>>::exists() {
+				<Equivocation<T, I>>::set(Some(EquivocationEvidence {
+					slot,
+					finalized_block_root,
+					conflicting_block_root,
+					attested_header: update.attested_header,
+					signature_slot: update.signature_slot,
+				}));
+			}
+			<OperatingMode<T, I>>::set(Mode::Blocked);
+
+			log::error!(
+				target: "ethereum-beacon-client",
+				"💫 Conflicting finalized headers {} and {} at slot {}. Light client halted.",
+				finalized_block_root,
+				conflicting_block_root,
+				slot
+			);
+
+			Self::deposit_event(Event::EquivocationDetected {
+				slot,
+				finalized_block_root,
+				conflicting_block_root,
+			});
+			Self::deposit_event(Event::OperatingModeChanged { mode: Mode::Blocked });
+		}
+
 		/// Stores the provided execution header in pallet storage. The header is stored
-		/// in a ring buffer map, with the block hash as map key. The last imported execution
-		/// header is also kept in storage, for the relayer to check import progress.
+		/// in a ring buffer map, with the block hash as map key. The execution header with the
+		/// highest block number is also kept in storage, for the relayer to check import progress.
 		pub(crate) fn store_execution_header(
 			block_hash: H256,
 			header: CompactExecutionHeader,
@@ -650,7 +1387,7 @@ pub mod pallet {
 		) {
 			let block_number = header.block_number;
 
-			<ExecutionHeaderBuffer<T>>::insert(block_hash, header);
+			<ExecutionHeaderBuffer<T, I>>::insert(block_hash, header);
 
 			log::trace!(
 				target: "ethereum-beacon-client",
@@ -659,11 +1396,13 @@ pub mod pallet {
 				block_number
 			);
 
-			LatestExecutionState::<T>::mutate(|s| {
-				s.beacon_block_root = beacon_block_root;
-				s.beacon_slot = beacon_slot;
-				s.block_hash = block_hash;
-				s.block_number = block_number;
+			LatestExecutionState::<T, I>::mutate(|s| {
+				if block_number > s.block_number {
+					s.beacon_block_root = beacon_block_root;
+					s.beacon_slot = beacon_slot;
+					s.block_hash = block_hash;
+					s.block_number = block_number;
+				}
 			});
 
 			Self::deposit_event(Event::ExecutionHeaderImported { block_hash, block_number });
@@ -674,7 +1413,7 @@ pub mod pallet {
 		/// (used in conjunction with the fork version).
 		/// https://eth2book.info/capella/part3/containers/state/#genesis_validators_root
 		fn store_validators_root(validators_root: H256) {
-			<ValidatorsRoot<T>>::set(validators_root);
+			<ValidatorsRoot<T, I>>::set(validators_root);
 		}
 
 		/// Returns the domain for the domain_type and fork_version. The domain is used to
@@ -708,7 +1447,7 @@ pub mod pallet {
 				genesis_validators_root: genesis_validators_root.into(),
 			}
 			.hash_tree_root()
-			.map_err(|_| Error::<T>::ForkDataHashTreeRootFailed)?;
+			.map_err(|_| Error::<T, I>::ForkDataHashTreeRootFailed)?;
 
 			Ok(hash_root)
 		}
@@ -722,17 +1461,23 @@ pub mod pallet {
 			let sync_committee_sum = sync_committee_sum(sync_committee_bits);
 			ensure!(
 				((sync_committee_sum * 3) as usize) >= sync_committee_bits.len() * 2,
-				Error::<T>::SyncCommitteeParticipantsNotSupermajority
+				Error::<T, I>::SyncCommitteeParticipantsNotSupermajority
 			);
 
 			Ok(())
 		}
 
 		/// Returns the fork version based on the current epoch. The hard fork versions
-		/// are defined in pallet config.
+		/// are defined in the fork schedule.
 		pub(super) fn compute_fork_version(epoch: u64) -> ForkVersion {
-			let fork_versions = T::ForkVersions::get();
+			let fork_versions = <ForkSchedule<T, I>>::get();
 
+			if epoch >= fork_versions.electra.epoch {
+				return fork_versions.electra.version
+			}
+			if epoch >= fork_versions.deneb.epoch {
+				return fork_versions.deneb.version
+			}
 			if epoch >= fork_versions.capella.epoch {
 				return fork_versions.capella.version
 			}
@@ -746,6 +1491,118 @@ pub mod pallet {
 			fork_versions.genesis.version
 		}
 
+		/// Verifies that the forks of a new fork schedule are in order of activation. Forks that
+		/// are active at the latest finalized header must be left unchanged, while the others
+		/// must be scheduled after it.
+		pub(super) fn verify_fork_schedule(fork_versions: &ForkVersions) -> DispatchResult {
+			let forks = fork_versions.forks();
+			ensure!(
+				forks.windows(2).all(|pair| pair[0].epoch <= pair[1].epoch),
+				Error::<T, I>::InvalidForkSchedule
+			);
+
+			// Before bootstrapping, no fork is active yet.
+			let latest_finalized_state =
+				match FinalizedBeaconState::<T, I>::get(LatestFinalizedBlockRoot::<T, I>::get()) {
+					Some(state) => state,
+					None => return Ok(()),
+				};
+			let epoch =
+				compute_epoch(latest_finalized_state.slot, T::BeaconSpec::SLOTS_PER_EPOCH as u64);
+
+			let current_fork_versions = <ForkSchedule<T, I>>::get();
+			for (fork, current_fork) in forks.iter().zip(current_fork_versions.forks()) {
+				if current_fork.epoch <= epoch {
+					ensure!(*fork == current_fork, Error::<T, I>::InvalidForkSchedule);
+				} else {
+					ensure!(fork.epoch > epoch, Error::<T, I>::InvalidForkSchedule);
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Checks that the version of an execution header matches the fork active at the given
+		/// beacon slot.
+		pub(super) fn execution_header_version_matches(
+			execution_header: &VersionedExecutionPayloadHeader,
+			slot: u64,
+		) -> bool {
+			let epoch = compute_epoch(slot, T::BeaconSpec::SLOTS_PER_EPOCH as u64);
+			let is_deneb = epoch >= <ForkSchedule<T, I>>::get().deneb.epoch;
+			match execution_header {
+				VersionedExecutionPayloadHeader::Capella(_) => !is_deneb,
+				VersionedExecutionPayloadHeader::Deneb(_) => is_deneb,
+			}
+		}
+
+		/// Returns whether the pallet has been halted by governance.
+		pub(super) fn is_halted() -> bool {
+			Self::operating_mode() == Mode::Blocked
+		}
+
+		/// Returns whether the Electra fork is active at the given slot. Electra changes the
+		/// beacon state layout, so proofs against a beacon state need fork specific generalized
+		/// indices.
+		pub(super) fn is_electra(slot: u64) -> bool {
+			compute_epoch(slot, T::BeaconSpec::SLOTS_PER_EPOCH as u64) >=
+				<ForkSchedule<T, I>>::get().electra.epoch
+		}
+
+		/// Returns the generalized index of the finalized checkpoint root in the beacon state of
+		/// the given slot.
+		pub(super) fn finalized_root_gindex_at_slot(slot: u64) -> usize {
+			if Self::is_electra(slot) {
+				return config::electra::FINALIZED_ROOT_INDEX
+			}
+			config::altair::FINALIZED_ROOT_INDEX
+		}
+
+		/// Returns the generalized index of the current sync committee in the beacon state of the
+		/// given slot.
+		pub(super) fn current_sync_committee_gindex_at_slot(slot: u64) -> usize {
+			if Self::is_electra(slot) {
+				return config::electra::CURRENT_SYNC_COMMITTEE_INDEX
+			}
+			config::altair::CURRENT_SYNC_COMMITTEE_INDEX
+		}
+
+		/// Returns the generalized index of the next sync committee in the beacon state of the
+		/// given slot.
+		pub(super) fn next_sync_committee_gindex_at_slot(slot: u64) -> usize {
+			if Self::is_electra(slot) {
+				return config::electra::NEXT_SYNC_COMMITTEE_INDEX
+			}
+			config::altair::NEXT_SYNC_COMMITTEE_INDEX
+		}
+
+		/// Returns the generalized index of the `block_roots` field in the beacon state of the
+		/// given slot.
+		pub(super) fn block_roots_gindex_at_slot(slot: u64) -> usize {
+			if Self::is_electra(slot) {
+				return config::electra::BLOCK_ROOTS_INDEX
+			}
+			config::altair::BLOCK_ROOTS_INDEX
+		}
+
+		/// Returns the generalized index of the `historical_summaries` field in the beacon state of
+		/// the given slot.
+		pub(super) fn historical_summaries_gindex_at_slot(slot: u64) -> usize {
+			if Self::is_electra(slot) {
+				return config::electra::HISTORICAL_SUMMARIES_INDEX
+			}
+			config::altair::HISTORICAL_SUMMARIES_INDEX
+		}
+
+		/// Returns the generalized index of the execution payload in the beacon block body of the
+		/// given slot.
+		pub(super) fn execution_header_gindex_at_slot(slot: u64) -> usize {
+			if Self::is_electra(slot) {
+				return config::electra::EXECUTION_HEADER_INDEX
+			}
+			config::altair::EXECUTION_HEADER_INDEX
+		}
+
 		/// Returns a vector of public keys that participated in the sync committee block signage.
 		/// Sync committee bits is an array of 0s and 1s, 0 meaning the corresponding sync committee
 		/// member did not participate in the vote, 1 meaning they participated.
@@ -754,10 +1611,10 @@ pub mod pallet {
 		/// return participating members.
 		pub fn find_pubkeys(
 			sync_committee_bits: &[u8],
-			sync_committee_pubkeys: &[PublicKeyPrepared],
+			sync_committee_pubkeys: &[PublicKey],
 			participant: bool,
-		) -> Vec<PublicKeyPrepared> {
-			let mut pubkeys: Vec<PublicKeyPrepared> = Vec::new();
+		) -> Vec<PublicKey> {
+			let mut pubkeys: Vec<PublicKey> = Vec::new();
 			for (bit, pubkey) in sync_committee_bits.iter().zip(sync_committee_pubkeys.iter()) {
 				if *bit == u8::from(participant) {
 					pubkeys.push(*pubkey);
@@ -775,7 +1632,7 @@ pub mod pallet {
 		) -> Result<H256, DispatchError> {
 			let fork_version = Self::compute_fork_version(compute_epoch(
 				signature_slot,
-				config::SLOTS_PER_EPOCH as u64,
+				T::BeaconSpec::SLOTS_PER_EPOCH as u64,
 			));
 			let domain_type = config::DOMAIN_SYNC_COMMITTEE.to_vec();
 			// Domains are used for for seeds, for signatures, and for selecting aggregators.
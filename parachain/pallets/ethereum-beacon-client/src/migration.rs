@@ -0,0 +1,270 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+//! Storage migrations for the beacon client pallet.
+use crate::{
+	types::SyncCommitteePrepared, BeaconSpec, Config, CurrentSyncCommittee, ExecutionHeaders,
+	NextSyncCommittee, Pallet,
+};
+use codec::{Decode, DecodeAll, Encode};
+use frame_support::{
+	ensure, log,
+	storage::unhashed,
+	traits::{ConstU32, Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+	BoundedVec,
+};
+use primitives::{
+	config::{MAINNET_SYNC_COMMITTEE_SIZE, MAX_SYNC_COMMITTEE_SIZE, MINIMAL_SYNC_COMMITTEE_SIZE},
+	CompactExecutionHeader, PublicKey, PublicKeyPrepared,
+};
+use sp_core::H256;
+use sp_std::{boxed::Box, marker::PhantomData, prelude::*};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Sync committees converted to a new storage layout, by storage key.
+type Translated<New> = Vec<([u8; 32], New)>;
+
+/// Decodes the stored sync committees with the `Old` layout and converts them, without writing
+/// anything.
+fn translate_sync_committees<T: Config<I>, I: 'static, Old: Decode, New>(
+	convert: impl Fn(Old) -> Result<New, &'static str>,
+) -> Result<Translated<New>, &'static str> {
+	[CurrentSyncCommittee::<T, I>::hashed_key(), NextSyncCommittee::<T, I>::hashed_key()]
+		.into_iter()
+		.filter_map(|key| unhashed::get_raw(&key).map(|raw| (key, raw)))
+		.map(|(key, raw)| {
+			let old =
+				Old::decode_all(&mut &raw[..]).map_err(|_| "Sync committee cannot be decoded")?;
+			Ok((key, convert(old)?))
+		})
+		.collect()
+}
+
+/// Migrates the stored sync committees from the `Old` to the `New` layout and bumps the storage
+/// version to `version`. Both committees are converted before anything is written, so that a
+/// failure leaves the storage and its version untouched.
+fn migrate_sync_committees<T: Config<I>, I: 'static, Old: Decode, New: Encode>(
+	version: u16,
+	convert: impl Fn(Old) -> Result<New, &'static str>,
+) -> Weight {
+	if Pallet::<T, I>::on_chain_storage_version() != version - 1 {
+		log::info!(
+			target: "ethereum-beacon-client",
+			"💫 Skipping sync committee migration to v{}",
+			version,
+		);
+		return T::DbWeight::get().reads(1)
+	}
+
+	match translate_sync_committees::<T, I, Old, New>(convert) {
+		Ok(translated) => {
+			for (key, new) in &translated {
+				unhashed::put(key, new);
+			}
+			StorageVersion::new(version).put::<Pallet<T, I>>();
+
+			log::info!(target: "ethereum-beacon-client", "💫 Migrated sync committees to v{}", version);
+			T::DbWeight::get().reads_writes(3, translated.len() as u64 + 1)
+		},
+		Err(err) => {
+			log::error!(
+				target: "ethereum-beacon-client",
+				"💥 Sync committee migration to v{} failed: {}",
+				version,
+				err,
+			);
+			T::DbWeight::get().reads(3)
+		},
+	}
+}
+
+#[cfg(feature = "try-runtime")]
+fn pre_upgrade_sync_committees<T: Config<I>, I: 'static, Old: Decode, New: Encode>(
+	version: u16,
+	convert: impl Fn(Old) -> Result<New, &'static str>,
+) -> Result<Vec<u8>, TryRuntimeError> {
+	if Pallet::<T, I>::on_chain_storage_version() != version - 1 {
+		return Ok(Vec::new())
+	}
+	Ok(translate_sync_committees::<T, I, Old, New>(convert)?.encode())
+}
+
+#[cfg(feature = "try-runtime")]
+fn post_upgrade_sync_committees<T: Config<I>, I: 'static, New: Decode + PartialEq>(
+	version: u16,
+	state: Vec<u8>,
+) -> Result<(), TryRuntimeError> {
+	if state.is_empty() {
+		return Ok(())
+	}
+	ensure!(
+		Pallet::<T, I>::on_chain_storage_version() == version,
+		"Sync committee storage version not bumped"
+	);
+	let translated = Translated::<New>::decode(&mut &state[..])
+		.map_err(|_| "Invalid sync committee pre-upgrade state")?;
+	for (key, new) in translated {
+		ensure!(unhashed::get::<New>(&key) == Some(new), "Sync committee not migrated");
+	}
+	Ok(())
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Sync committee as stored before v1, when the committee size was fixed at compile time by
+	/// the `beacon-spec-mainnet` feature.
+	#[derive(Encode, Decode)]
+	pub struct SyncCommitteePreparedV0<const COMMITTEE_SIZE: usize> {
+		pub root: H256,
+		pub pubkeys: Box<[PublicKeyPrepared; COMMITTEE_SIZE]>,
+		pub aggregate_pubkey: PublicKeyPrepared,
+	}
+
+	/// Sync committee as stored in v1, with all public keys decompressed.
+	#[derive(Encode, Decode, PartialEq)]
+	pub struct SyncCommitteePreparedV1 {
+		pub root: H256,
+		pub pubkeys: BoundedVec<PublicKeyPrepared, ConstU32<MAX_SYNC_COMMITTEE_SIZE>>,
+		pub aggregate_pubkey: PublicKeyPrepared,
+	}
+
+	/// Stores the sync committees with a length prefixed list of public keys, so that the
+	/// committee size is no longer part of the storage layout. `COMMITTEE_SIZE` must be the sync
+	/// committee size of the preset the runtime was built with before the upgrade.
+	pub struct MigrateToV1<T, const COMMITTEE_SIZE: usize, I = ()>(PhantomData<(T, I)>);
+
+	/// Migration for runtimes previously built without the `beacon-spec-mainnet` feature.
+	pub type MigrateMinimalToV1<T, I = ()> = MigrateToV1<T, MINIMAL_SYNC_COMMITTEE_SIZE, I>;
+	/// Migration for runtimes previously built with the `beacon-spec-mainnet` feature.
+	pub type MigrateMainnetToV1<T, I = ()> = MigrateToV1<T, MAINNET_SYNC_COMMITTEE_SIZE, I>;
+
+	impl<T: Config<I>, const COMMITTEE_SIZE: usize, I: 'static> MigrateToV1<T, COMMITTEE_SIZE, I> {
+		fn convert(
+			old: SyncCommitteePreparedV0<COMMITTEE_SIZE>,
+		) -> Result<SyncCommitteePreparedV1, &'static str> {
+			ensure!(
+				COMMITTEE_SIZE == T::BeaconSpec::SYNC_COMMITTEE_SIZE,
+				"Sync committee size does not match the preset"
+			);
+			Ok(SyncCommitteePreparedV1 {
+				root: old.root,
+				pubkeys: old.pubkeys.to_vec().try_into().map_err(|_| "Sync committee too large")?,
+				aggregate_pubkey: old.aggregate_pubkey,
+			})
+		}
+	}
+
+	impl<T: Config<I>, const COMMITTEE_SIZE: usize, I: 'static> OnRuntimeUpgrade
+		for MigrateToV1<T, COMMITTEE_SIZE, I>
+	{
+		fn on_runtime_upgrade() -> Weight {
+			migrate_sync_committees::<T, I, _, _>(1, Self::convert)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			pre_upgrade_sync_committees::<T, I, _, _>(1, Self::convert)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			post_upgrade_sync_committees::<T, I, SyncCommitteePreparedV1>(1, state)
+		}
+	}
+}
+
+pub mod v2 {
+	use super::{v1::SyncCommitteePreparedV1, *};
+
+	/// Compresses the public keys of the stored sync committees, keeping the prepared aggregate
+	/// public key.
+	pub struct MigrateToV2<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> MigrateToV2<T, I> {
+		fn convert(old: SyncCommitteePreparedV1) -> Result<SyncCommitteePrepared, &'static str> {
+			let pubkeys: Vec<PublicKey> =
+				old.pubkeys.iter().map(|pubkey| PublicKey(pubkey.as_bytes())).collect();
+			Ok(SyncCommitteePrepared {
+				root: old.root,
+				pubkeys: pubkeys.try_into().map_err(|_| "Sync committee too large")?,
+				aggregate_pubkey: old.aggregate_pubkey,
+			})
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV2<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			migrate_sync_committees::<T, I, _, _>(2, Self::convert)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			pre_upgrade_sync_committees::<T, I, _, _>(2, Self::convert)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			post_upgrade_sync_committees::<T, I, SyncCommitteePrepared>(2, state)
+		}
+	}
+}
+
+pub mod v3 {
+	use super::*;
+
+	/// Execution header as stored before v3, without the transactions root.
+	#[derive(Encode, Decode)]
+	pub struct CompactExecutionHeaderV2 {
+		pub parent_hash: H256,
+		#[codec(compact)]
+		pub block_number: u64,
+		pub state_root: H256,
+		pub receipts_root: H256,
+	}
+
+	/// Adds an unknown transactions root to the stored execution headers.
+	pub struct MigrateToV3<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV3<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 2 {
+				log::info!(target: "ethereum-beacon-client", "💫 Skipping execution header migration to v3");
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut count = 0u64;
+			ExecutionHeaders::<T, I>::translate_values(|old: CompactExecutionHeaderV2| {
+				count += 1;
+				Some(CompactExecutionHeader {
+					parent_hash: old.parent_hash,
+					block_number: old.block_number,
+					state_root: old.state_root,
+					receipts_root: old.receipts_root,
+					transactions_root: None,
+				})
+			});
+			StorageVersion::new(3).put::<Pallet<T, I>>();
+
+			log::info!(target: "ethereum-beacon-client", "💫 Migrated {} execution headers to v3", count);
+			T::DbWeight::get().reads_writes(count + 1, count + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((ExecutionHeaders::<T, I>::iter_keys().count() as u64).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let count = u64::decode(&mut &state[..]).map_err(|_| "Invalid pre-upgrade state")?;
+			ensure!(
+				ExecutionHeaders::<T, I>::iter_values().count() as u64 == count,
+				"Execution headers lost during migration"
+			);
+			Ok(())
+		}
+	}
+}
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
 use crate as ethereum_beacon_client;
-use frame_support::parameter_types;
+use frame_support::{instances::Instance1, parameter_types, traits::GenesisBuild};
 use pallet_timestamp;
 use primitives::{Fork, ForkVersions};
 use sp_core::H256;
@@ -9,14 +9,20 @@ use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
 };
+use std::{fs::File, path::PathBuf};
+
+fn load_fixture<T>(basename: &str) -> Result<T, serde_json::Error>
+where
+	T: for<'de> serde::Deserialize<'de>,
+{
+	let filepath: PathBuf =
+		[env!("CARGO_MANIFEST_DIR"), "tests", "fixtures", basename].iter().collect();
+	serde_json::from_reader(File::open(&filepath).unwrap())
+}
 
-#[cfg(not(feature = "beacon-spec-mainnet"))]
 pub mod minimal {
 	use super::*;
 
-	use crate::config;
-	use std::{fs::File, path::PathBuf};
-
 	type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 	type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -29,6 +35,7 @@ pub mod minimal {
 			System: frame_system::{Pallet, Call, Storage, Event<T>},
 			Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
 			EthereumBeaconClient: ethereum_beacon_client::{Pallet, Call, Storage, Event<T>},
+			OtherBeaconClient: ethereum_beacon_client::<Instance1>::{Pallet, Call, Storage, Event<T>},
 		}
 	);
 
@@ -73,6 +80,10 @@ pub mod minimal {
 
 	parameter_types! {
 		pub const ExecutionHeadersPruneThreshold: u32 = 10;
+		pub const MaxFreeUpdatesPerBlock: u32 = 2;
+		pub const MaxUpdatesPerBatch: u32 = 4;
+		pub const UpdateTimeout: u64 = 25;
+		pub const WeakSubjectivityPeriod: u64 = 256;
 		pub const ChainForkVersions: ForkVersions = ForkVersions{
 			genesis: Fork {
 				version: [0, 0, 0, 1], // 0x00000001
@@ -90,64 +101,127 @@ pub mod minimal {
 				version: [3, 0, 0, 1], // 0x03000001
 				epoch: 0,
 			},
+			deneb: Fork {
+				version: [4, 0, 0, 1], // 0x04000001
+				epoch: 500,
+			},
+			electra: Fork {
+				version: [5, 0, 0, 1], // 0x05000001
+				epoch: 1000,
+			},
 		};
 	}
 
 	impl ethereum_beacon_client::Config for Test {
 		type RuntimeEvent = RuntimeEvent;
 		type ForkVersions = ChainForkVersions;
+		type ForkScheduleOrigin = frame_system::EnsureRoot<u64>;
 		type MaxExecutionHeadersToKeep = ExecutionHeadersPruneThreshold;
+		type BeaconSpec = crate::Minimal;
+		type MaxFreeUpdatesPerBlock = MaxFreeUpdatesPerBlock;
+		type MaxUpdatesPerBatch = MaxUpdatesPerBatch;
+		type UpdateTimeout = UpdateTimeout;
+		type WeakSubjectivityPeriod = WeakSubjectivityPeriod;
+		type WeightInfo = ();
+	}
+
+	parameter_types! {
+		pub const OtherChainForkVersions: ForkVersions = ForkVersions{
+			genesis: Fork {
+				version: [0, 0, 0, 2], // 0x00000002
+				epoch: 0,
+			},
+			altair: Fork {
+				version: [1, 0, 0, 2], // 0x01000002
+				epoch: 0,
+			},
+			bellatrix: Fork {
+				version: [2, 0, 0, 2], // 0x02000002
+				epoch: 0,
+			},
+			capella: Fork {
+				version: [3, 0, 0, 2], // 0x03000002
+				epoch: 0,
+			},
+			deneb: Fork {
+				version: [4, 0, 0, 2], // 0x04000002
+				epoch: 500,
+			},
+			electra: Fork {
+				version: [5, 0, 0, 2], // 0x05000002
+				epoch: 1000,
+			},
+		};
+	}
+
+	impl ethereum_beacon_client::Config<Instance1> for Test {
+		type RuntimeEvent = RuntimeEvent;
+		type ForkVersions = OtherChainForkVersions;
+		type ForkScheduleOrigin = frame_system::EnsureRoot<u64>;
+		type MaxExecutionHeadersToKeep = ExecutionHeadersPruneThreshold;
+		type BeaconSpec = crate::Minimal;
+		type MaxFreeUpdatesPerBlock = MaxFreeUpdatesPerBlock;
+		type MaxUpdatesPerBatch = MaxUpdatesPerBatch;
+		type UpdateTimeout = UpdateTimeout;
+		type WeakSubjectivityPeriod = WeakSubjectivityPeriod;
 		type WeightInfo = ();
 	}
 
 	// Build genesis storage according to the mock runtime.
 	pub fn new_tester() -> sp_io::TestExternalities {
-		let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+		new_tester_with_config(Default::default())
+	}
+
+	// Build genesis storage according to the mock runtime, with the given genesis config of the
+	// light client.
+	pub fn new_tester_with_config(
+		config: ethereum_beacon_client::GenesisConfig,
+	) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+		GenesisBuild::<Test>::assimilate_storage(&config, &mut t).unwrap();
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| Timestamp::set_timestamp(30_000));
 		ext
 	}
 
-	fn load_fixture<T>(basename: &str) -> Result<T, serde_json::Error>
-	where
-		T: for<'de> serde::Deserialize<'de>,
-	{
-		let filepath: PathBuf =
-			[env!("CARGO_MANIFEST_DIR"), "tests", "fixtures", basename].iter().collect();
-		serde_json::from_reader(File::open(&filepath).unwrap())
-	}
-
 	pub fn load_execution_header_update_fixture() -> primitives::ExecutionHeaderUpdate {
 		load_fixture("execution-header-update.minimal.json").unwrap()
 	}
 
-	pub fn load_checkpoint_update_fixture(
-	) -> primitives::CheckpointUpdate<{ config::SYNC_COMMITTEE_SIZE }> {
+	pub fn load_execution_header_update_deneb_fixture() -> primitives::ExecutionHeaderUpdate {
+		load_fixture("execution-header-update.deneb.minimal.json").unwrap()
+	}
+
+	pub fn load_execution_header_update_historical_summaries_fixture(
+	) -> primitives::ExecutionHeaderUpdate {
+		load_fixture("execution-header-update.historical-summaries.minimal.json").unwrap()
+	}
+
+	pub fn load_checkpoint_update_fixture() -> primitives::CheckpointUpdate {
 		load_fixture("initial-checkpoint.minimal.json").unwrap()
 	}
 
-	pub fn load_sync_committee_update_fixture(
-	) -> primitives::Update<{ config::SYNC_COMMITTEE_SIZE }, { config::SYNC_COMMITTEE_BITS_SIZE }> {
+	pub fn load_checkpoint_update_electra_fixture() -> primitives::CheckpointUpdate {
+		load_fixture("initial-checkpoint.electra.minimal.json").unwrap()
+	}
+
+	pub fn load_sync_committee_update_fixture() -> primitives::Update {
 		load_fixture("sync-committee-update.minimal.json").unwrap()
 	}
 
-	pub fn load_finalized_header_update_fixture(
-	) -> primitives::Update<{ config::SYNC_COMMITTEE_SIZE }, { config::SYNC_COMMITTEE_BITS_SIZE }> {
+	pub fn load_finalized_header_update_fixture() -> primitives::Update {
 		load_fixture("finalized-header-update.minimal.json").unwrap()
 	}
 
-	pub fn load_next_sync_committee_update_fixture(
-	) -> primitives::Update<{ config::SYNC_COMMITTEE_SIZE }, { config::SYNC_COMMITTEE_BITS_SIZE }> {
+	pub fn load_next_sync_committee_update_fixture() -> primitives::Update {
 		load_fixture("next-sync-committee-update.minimal.json").unwrap()
 	}
 
-	pub fn load_next_finalized_header_update_fixture(
-	) -> primitives::Update<{ config::SYNC_COMMITTEE_SIZE }, { config::SYNC_COMMITTEE_BITS_SIZE }> {
+	pub fn load_next_finalized_header_update_fixture() -> primitives::Update {
 		load_fixture("next-finalized-header-update.minimal.json").unwrap()
 	}
 }
 
-#[cfg(feature = "beacon-spec-mainnet")]
 pub mod mainnet {
 	use super::*;
 
@@ -223,14 +297,32 @@ pub mod mainnet {
 				version: [3, 0, 16, 32], // 0x03001020
 				epoch: 162304,
 			},
+			deneb: Fork {
+				version: [4, 0, 16, 32], // 0x04001020
+				epoch: 231680,
+			},
+			electra: Fork {
+				version: [5, 0, 16, 32], // 0x05001020
+				epoch: u64::MAX,
+			},
 		};
 		pub const ExecutionHeadersPruneThreshold: u32 = 10;
+		pub const MaxFreeUpdatesPerBlock: u32 = 2;
+		pub const MaxUpdatesPerBatch: u32 = 4;
+		pub const UpdateTimeout: u64 = 8192;
+		pub const WeakSubjectivityPeriod: u64 = 8192 * 4;
 	}
 
 	impl ethereum_beacon_client::Config for Test {
 		type RuntimeEvent = RuntimeEvent;
 		type ForkVersions = ChainForkVersions;
+		type ForkScheduleOrigin = frame_system::EnsureRoot<u64>;
 		type MaxExecutionHeadersToKeep = ExecutionHeadersPruneThreshold;
+		type BeaconSpec = crate::Mainnet;
+		type MaxFreeUpdatesPerBlock = MaxFreeUpdatesPerBlock;
+		type MaxUpdatesPerBatch = MaxUpdatesPerBatch;
+		type UpdateTimeout = UpdateTimeout;
+		type WeakSubjectivityPeriod = WeakSubjectivityPeriod;
 		type WeightInfo = ();
 	}
 
@@ -241,4 +333,8 @@ pub mod mainnet {
 		ext.execute_with(|| Timestamp::set_timestamp(30_000));
 		ext
 	}
+
+	pub fn load_execution_header_update_deneb_fixture() -> primitives::ExecutionHeaderUpdate {
+		load_fixture("execution-header-update.deneb.mainnet.json").unwrap()
+	}
 }
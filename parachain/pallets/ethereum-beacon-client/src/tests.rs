@@ -1,16 +1,35 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
 use crate::{
-	functions::compute_period, mock::minimal::*, pallet::ExecutionHeaders, sync_committee_sum,
-	verify_merkle_branch, BeaconHeader, CompactBeaconState, Error, FinalizedBeaconState,
-	LatestFinalizedBlockRoot, NextSyncCommittee,
+	functions::{compute_epoch, compute_period},
+	mock::minimal::*,
+	pallet::ExecutionHeaders,
+	sync_committee_sum,
+	types::AncestryProof,
+	verify_merkle_branch, BeaconHeader, BeaconSpec, BestValidUpdate, CompactBeaconState,
+	CurrentSyncCommittee, Error, FinalizedBeaconState, FinalizedBlockRoots, FreeUpdatesInBlock, Gnosis,
+	LatestFinalizedBlockRoot, Mainnet, Minimal, NextSyncCommittee,
 };
 
-use frame_support::{assert_err, assert_ok};
+use frame_support::{
+	assert_err, assert_noop, assert_ok,
+	dispatch::Pays,
+	instances::Instance1,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
 use hex_literal::hex;
-use primitives::{CompactExecutionHeader, NextSyncCommitteeUpdate};
+use primitives::{
+	decompress_sync_committee_bits, fast_aggregate_verify_host,
+	merkle_proof::{compute_merkle_root, generalized_index_length, subtree_index},
+	BlsError, CheckpointUpdate, CompactExecutionHeader, Mode, NextSyncCommitteeUpdate, PublicKey,
+	Signature, SyncCommittee, SyncCommitteePrepared, Update, VersionedExecutionPayloadHeader,
+};
 use rand::{thread_rng, Rng};
-use sp_core::H256;
+use snowbridge_core::{Message, Proof, StateVerifier, TransactionVerifier, Verifier};
+use snowbridge_ethereum::{Bloom, Log, Receipt};
+use snowbridge_testutils::state_proof;
+use sp_core::{H160, H256, U256};
+use sp_runtime::DispatchError;
 
 #[test]
 pub fn sum_sync_committee_participation() {
@@ -157,6 +176,147 @@ pub fn compute_domain_bls() {
 	});
 }
 
+#[test]
+pub fn compute_fork_version() {
+	new_tester().execute_with(|| {
+		let fork_versions = ChainForkVersions::get();
+		assert_eq!(
+			EthereumBeaconClient::compute_fork_version(fork_versions.deneb.epoch - 1),
+			fork_versions.capella.version
+		);
+		assert_eq!(
+			EthereumBeaconClient::compute_fork_version(fork_versions.deneb.epoch),
+			fork_versions.deneb.version
+		);
+		assert_eq!(
+			EthereumBeaconClient::compute_fork_version(fork_versions.electra.epoch - 1),
+			fork_versions.deneb.version
+		);
+		assert_eq!(
+			EthereumBeaconClient::compute_fork_version(fork_versions.electra.epoch),
+			fork_versions.electra.version
+		);
+	});
+}
+
+#[test]
+pub fn compute_period_per_beacon_spec() {
+	assert_eq!(compute_period::<Minimal>(63), 0);
+	assert_eq!(compute_period::<Minimal>(64), 1);
+	assert_eq!(compute_period::<Mainnet>(8191), 0);
+	assert_eq!(compute_period::<Mainnet>(8192), 1);
+	assert_eq!(compute_period::<Gnosis>(8191), 0);
+	assert_eq!(compute_period::<Gnosis>(8192), 1);
+	assert_eq!(Gnosis::SYNC_COMMITTEE_BITS_SIZE, 64);
+	assert_eq!(Minimal::SYNC_COMMITTEE_BITS_SIZE, 4);
+}
+
+fn sync_committee_v0(
+	sync_committee: &primitives::SyncCommittee,
+) -> crate::migration::v1::SyncCommitteePreparedV0<{ Minimal::SYNC_COMMITTEE_SIZE }> {
+	crate::migration::v1::SyncCommitteePreparedV0 {
+		root: sync_committee.hash_tree_root().unwrap(),
+		pubkeys: primitives::prepare_g1_pubkeys(&sync_committee.pubkeys)
+			.unwrap()
+			.try_into()
+			.unwrap(),
+		aggregate_pubkey: primitives::bls::prepare_milagro_pubkey(&sync_committee.aggregate_pubkey)
+			.unwrap(),
+	}
+}
+
+#[test]
+fn migrate_sync_committees_to_v1() {
+	use codec::Encode;
+
+	let checkpoint = load_checkpoint_update_fixture();
+	let sync_committee = &checkpoint.current_sync_committee;
+
+	new_tester().execute_with(|| {
+		StorageVersion::new(0).put::<EthereumBeaconClient>();
+		frame_support::storage::unhashed::put_raw(
+			&<crate::CurrentSyncCommittee<Test>>::hashed_key(),
+			&sync_committee_v0(sync_committee).encode(),
+		);
+
+		crate::migration::v1::MigrateMinimalToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(EthereumBeaconClient::on_chain_storage_version(), 1);
+		let migrated: crate::migration::v1::SyncCommitteePreparedV1 =
+			frame_support::storage::unhashed::get(
+				&<crate::CurrentSyncCommittee<Test>>::hashed_key(),
+			)
+			.unwrap();
+		assert!(migrated.pubkeys[..] == sync_committee_v0(sync_committee).pubkeys[..]);
+		assert!(!<NextSyncCommittee<Test>>::exists());
+	});
+}
+
+#[test]
+fn migrate_sync_committees_to_v1_with_wrong_preset() {
+	use codec::Encode;
+	use frame_support::storage::unhashed;
+
+	let checkpoint = load_checkpoint_update_fixture();
+	let encoded = sync_committee_v0(&checkpoint.current_sync_committee).encode();
+
+	new_tester().execute_with(|| {
+		StorageVersion::new(0).put::<EthereumBeaconClient>();
+		let key = <crate::CurrentSyncCommittee<Test>>::hashed_key();
+		unhashed::put_raw(&key, &encoded);
+
+		crate::migration::v1::MigrateMainnetToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(EthereumBeaconClient::on_chain_storage_version(), 0);
+		assert_eq!(unhashed::get_raw(&key), Some(encoded));
+	});
+}
+
+#[test]
+pub fn generalized_indices_follow_fork_schedule() {
+	new_tester().execute_with(|| {
+		let electra_slot = ChainForkVersions::get().electra.epoch * Minimal::SLOTS_PER_EPOCH as u64;
+
+		assert_eq!(
+			EthereumBeaconClient::finalized_root_gindex_at_slot(electra_slot - 1),
+			crate::config::altair::FINALIZED_ROOT_INDEX
+		);
+		assert_eq!(
+			EthereumBeaconClient::current_sync_committee_gindex_at_slot(electra_slot - 1),
+			crate::config::altair::CURRENT_SYNC_COMMITTEE_INDEX
+		);
+		assert_eq!(
+			EthereumBeaconClient::next_sync_committee_gindex_at_slot(electra_slot - 1),
+			crate::config::altair::NEXT_SYNC_COMMITTEE_INDEX
+		);
+		assert_eq!(
+			EthereumBeaconClient::block_roots_gindex_at_slot(electra_slot - 1),
+			crate::config::altair::BLOCK_ROOTS_INDEX
+		);
+
+		assert_eq!(
+			EthereumBeaconClient::finalized_root_gindex_at_slot(electra_slot),
+			crate::config::electra::FINALIZED_ROOT_INDEX
+		);
+		assert_eq!(
+			EthereumBeaconClient::current_sync_committee_gindex_at_slot(electra_slot),
+			crate::config::electra::CURRENT_SYNC_COMMITTEE_INDEX
+		);
+		assert_eq!(
+			EthereumBeaconClient::next_sync_committee_gindex_at_slot(electra_slot),
+			crate::config::electra::NEXT_SYNC_COMMITTEE_INDEX
+		);
+		assert_eq!(
+			EthereumBeaconClient::block_roots_gindex_at_slot(electra_slot),
+			crate::config::electra::BLOCK_ROOTS_INDEX
+		);
+		assert_eq!(
+			EthereumBeaconClient::execution_header_gindex_at_slot(electra_slot),
+			crate::config::electra::EXECUTION_HEADER_INDEX
+		);
+	});
+}
+
 #[test]
 pub fn verify_merkle_branch_for_finalized_root() {
 	new_tester().execute_with(|| {
@@ -171,8 +331,8 @@ pub fn verify_merkle_branch_for_finalized_root() {
 					hex!("d2dc4ba9fd4edff6716984136831e70a6b2e74fca27b8097a820cbbaa5a6e3c3").into(),
 					hex!("91f77a19d8afa4a08e81164bb2e570ecd10477b3b65c305566a6d2be88510584").into(),
 				],
-				crate::config::FINALIZED_ROOT_INDEX,
-				crate::config::FINALIZED_ROOT_DEPTH,
+				subtree_index(crate::config::altair::FINALIZED_ROOT_INDEX),
+				generalized_index_length(crate::config::altair::FINALIZED_ROOT_INDEX),
 				hex!("e46559327592741956f6beaa0f52e49625eb85dce037a0bd2eff333c743b287f").into()
 			),
 			true
@@ -191,8 +351,8 @@ pub fn verify_merkle_branch_fails_if_depth_and_branch_dont_match() {
 					hex!("5f6f02af29218292d21a69b64a794a7c0873b3e0f54611972863706e8cbdf371").into(),
 					hex!("e7125ff9ab5a840c44bedb4731f440a405b44e15f2d1a89e27341b432fabe13d").into(),
 				],
-				crate::config::FINALIZED_ROOT_INDEX,
-				crate::config::FINALIZED_ROOT_DEPTH,
+				subtree_index(crate::config::altair::FINALIZED_ROOT_INDEX),
+				generalized_index_length(crate::config::altair::FINALIZED_ROOT_INDEX),
 				hex!("e46559327592741956f6beaa0f52e49625eb85dce037a0bd2eff333c743b287f").into()
 			),
 			false
@@ -205,7 +365,7 @@ pub fn sync_committee_participation_is_supermajority() {
 	let bits =
 	hex!("bffffffff7f1ffdfcfeffeffbfdffffbfffffdffffefefffdffff7f7ffff77fffdf7bff77ffdf7fffafffffff77fefffeff7effffffff5f7fedfffdfb6ddff7b"
 	);
-	let participation = primitives::decompress_sync_committee_bits::<512, 64>(bits);
+	let participation = primitives::decompress_sync_committee_bits(&bits);
 	assert_ok!(EthereumBeaconClient::sync_committee_participation_is_supermajority(&participation));
 }
 
@@ -306,12 +466,39 @@ fn process_initial_checkpoint() {
 	});
 }
 
+#[test]
+fn process_initial_checkpoint_electra() {
+	let checkpoint = load_checkpoint_update_electra_fixture();
+	assert!(EthereumBeaconClient::is_electra(checkpoint.header.slot));
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		let block_root: H256 = checkpoint.header.hash_tree_root().unwrap();
+		assert!(<FinalizedBeaconState<Test>>::contains_key(block_root));
+	});
+}
+
+#[test]
+fn process_initial_checkpoint_electra_with_altair_proof_fails() {
+	let mut checkpoint = load_checkpoint_update_electra_fixture();
+	// Move the checkpoint before the Electra fork, so that its proofs are checked against the
+	// Altair generalized indices.
+	checkpoint.header.slot = load_checkpoint_update_fixture().header.slot;
+
+	new_tester().execute_with(|| {
+		assert_err!(
+			EthereumBeaconClient::process_checkpoint_update(&checkpoint),
+			Error::<Test>::InvalidSyncCommitteeMerkleProof
+		);
+	});
+}
+
 #[test]
 fn submit_update_in_current_period() {
 	let checkpoint = load_checkpoint_update_fixture();
 	let update = load_finalized_header_update_fixture();
-	let initial_period = compute_period(checkpoint.header.slot);
-	let update_period = compute_period(update.finalized_header.slot);
+	let initial_period = compute_period::<Minimal>(checkpoint.header.slot);
+	let update_period = compute_period::<Minimal>(update.finalized_header.slot);
 	assert_eq!(initial_period, update_period);
 
 	new_tester().execute_with(|| {
@@ -322,12 +509,95 @@ fn submit_update_in_current_period() {
 	});
 }
 
+#[test]
+fn submit_update_records_finalized_block_root() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let update = load_finalized_header_update_fixture();
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), update.clone()));
+		let block_root: H256 = update.finalized_header.hash_tree_root().unwrap();
+		assert_eq!(
+			EthereumBeaconClient::finalized_block_root_at_slot(update.finalized_header.slot),
+			Some(block_root)
+		);
+		assert_eq!(
+			EthereumBeaconClient::finalized_block_root_at_slot(checkpoint.header.slot),
+			Some(checkpoint.header.hash_tree_root().unwrap())
+		);
+	});
+}
+
+#[test]
+fn submit_update_with_conflicting_finality_halts() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let update = load_finalized_header_update_fixture();
+	let conflicting_block_root: H256 = update.finalized_header.hash_tree_root().unwrap();
+	let finalized_block_root = H256::repeat_byte(0x11);
+
+	new_tester().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		let latest_finalized_block_root = LatestFinalizedBlockRoot::<Test>::get();
+		// Pretend a different header was already finalized at the same slot
+		<FinalizedBlockRoots<Test>>::insert(update.finalized_header.slot, finalized_block_root);
+
+		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), update.clone()));
+
+		assert_eq!(EthereumBeaconClient::operating_mode(), Mode::Blocked);
+		assert_eq!(LatestFinalizedBlockRoot::<Test>::get(), latest_finalized_block_root);
+		let evidence = EthereumBeaconClient::equivocation().unwrap();
+		assert_eq!(evidence.slot, update.finalized_header.slot);
+		assert_eq!(evidence.finalized_block_root, finalized_block_root);
+		assert_eq!(evidence.conflicting_block_root, conflicting_block_root);
+		assert_eq!(evidence.attested_header, update.attested_header);
+		assert_eq!(evidence.signature_slot, update.signature_slot);
+		System::assert_has_event(RuntimeEvent::EthereumBeaconClient(
+			crate::Event::EquivocationDetected {
+				slot: update.finalized_header.slot,
+				finalized_block_root,
+				conflicting_block_root,
+			},
+		));
+
+		assert_noop!(
+			EthereumBeaconClient::submit(RuntimeOrigin::signed(1), update),
+			Error::<Test>::Halted
+		);
+	});
+}
+
+#[test]
+fn instances_are_independent() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let update = load_finalized_header_update_fixture();
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		assert_eq!(OtherBeaconClient::latest_finalized_block_root(), H256::zero());
+		assert_ok!(OtherBeaconClient::process_checkpoint_update(&checkpoint));
+		let checkpoint_root = OtherBeaconClient::latest_finalized_block_root();
+
+		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), update.clone()));
+		// The update is signed for the fork versions of the first instance only
+		assert_err!(
+			OtherBeaconClient::submit(RuntimeOrigin::signed(1), update.clone()),
+			Error::<Test, Instance1>::BLSVerificationFailed(BlsError::SignatureVerificationFailed)
+		);
+
+		let block_root: H256 = update.finalized_header.hash_tree_root().unwrap();
+		assert_eq!(EthereumBeaconClient::latest_finalized_block_root(), block_root);
+		assert_eq!(OtherBeaconClient::latest_finalized_block_root(), checkpoint_root);
+	});
+}
+
 #[test]
 fn submit_update_with_sync_committee_in_current_period() {
 	let checkpoint = load_checkpoint_update_fixture();
 	let update = load_sync_committee_update_fixture();
-	let init_period = compute_period(checkpoint.header.slot);
-	let update_period = compute_period(update.finalized_header.slot);
+	let init_period = compute_period::<Minimal>(checkpoint.header.slot);
+	let update_period = compute_period::<Minimal>(update.finalized_header.slot);
 	assert_eq!(init_period, update_period);
 
 	new_tester().execute_with(|| {
@@ -343,8 +613,9 @@ fn submit_update_in_next_period() {
 	let checkpoint = load_checkpoint_update_fixture();
 	let sync_committee_update = load_sync_committee_update_fixture();
 	let update = load_next_finalized_header_update_fixture();
-	let sync_committee_period = compute_period(sync_committee_update.finalized_header.slot);
-	let next_sync_committee_period = compute_period(update.finalized_header.slot);
+	let sync_committee_period =
+		compute_period::<Minimal>(sync_committee_update.finalized_header.slot);
+	let next_sync_committee_period = compute_period::<Minimal>(update.finalized_header.slot);
 	assert_eq!(sync_committee_period + 1, next_sync_committee_period);
 
 	new_tester().execute_with(|| {
@@ -364,8 +635,8 @@ fn submit_update_with_sync_committee_in_next_period() {
 	let checkpoint = load_checkpoint_update_fixture();
 	let update = load_sync_committee_update_fixture();
 	let next_update = load_next_sync_committee_update_fixture();
-	let update_period = compute_period(update.finalized_header.slot);
-	let next_update_period = compute_period(next_update.finalized_header.slot);
+	let update_period = compute_period::<Minimal>(update.finalized_header.slot);
+	let next_update_period = compute_period::<Minimal>(next_update.finalized_header.slot);
 	assert_eq!(update_period + 1, next_update_period);
 
 	new_tester().execute_with(|| {
@@ -376,11 +647,63 @@ fn submit_update_with_sync_committee_in_next_period() {
 		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), next_update.clone()));
 		let last_finalized_state =
 			FinalizedBeaconState::<Test>::get(LatestFinalizedBlockRoot::<Test>::get()).unwrap();
-		let last_synced_period = compute_period(last_finalized_state.slot);
+		let last_synced_period = compute_period::<Minimal>(last_finalized_state.slot);
 		assert_eq!(last_synced_period, next_update_period);
 	});
 }
 
+#[test]
+fn submit_batch_across_sync_committee_periods() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let update = load_sync_committee_update_fixture();
+	let next_update = load_next_sync_committee_update_fixture();
+	let next_update_period = compute_period::<Minimal>(next_update.finalized_header.slot);
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		// Without the first update, the next period update skips a sync committee period.
+		assert_err!(
+			EthereumBeaconClient::submit(RuntimeOrigin::signed(1), next_update.clone()),
+			Error::<Test>::SkippedSyncCommitteePeriod
+		);
+
+		let result = EthereumBeaconClient::submit_batch(
+			RuntimeOrigin::signed(1),
+			vec![update, next_update].try_into().unwrap(),
+		);
+		assert_eq!(result.unwrap().pays_fee, Pays::No);
+		let last_finalized_state =
+			FinalizedBeaconState::<Test>::get(LatestFinalizedBlockRoot::<Test>::get()).unwrap();
+		assert_eq!(compute_period::<Minimal>(last_finalized_state.slot), next_update_period);
+	});
+}
+
+#[test]
+fn submit_batch_is_atomic() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let update = load_sync_committee_update_fixture();
+	let mut next_update = load_next_sync_committee_update_fixture();
+	next_update.finality_branch[0] = H256::zero();
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		assert_noop!(
+			EthereumBeaconClient::submit_batch(
+				RuntimeOrigin::signed(1),
+				vec![update, next_update].try_into().unwrap(),
+			),
+			Error::<Test>::InvalidHeaderMerkleProof
+		);
+		assert_noop!(
+			EthereumBeaconClient::submit_batch(
+				RuntimeOrigin::signed(1),
+				vec![].try_into().unwrap()
+			),
+			Error::<Test>::EmptyBatch
+		);
+	});
+}
+
 #[test]
 fn submit_update_with_sync_committee_invalid_signature_slot() {
 	let checkpoint = load_checkpoint_update_fixture();
@@ -403,8 +726,9 @@ fn submit_update_with_sync_committee_invalid_signature_slot() {
 fn submit_update_with_skipped_sync_committee_period() {
 	let checkpoint = load_checkpoint_update_fixture();
 	let finalized_update = load_next_finalized_header_update_fixture();
-	let checkpoint_period = compute_period(checkpoint.header.slot);
-	let next_sync_committee_period = compute_period(finalized_update.finalized_header.slot);
+	let checkpoint_period = compute_period::<Minimal>(checkpoint.header.slot);
+	let next_sync_committee_period =
+		compute_period::<Minimal>(finalized_update.finalized_header.slot);
 	assert_eq!(checkpoint_period + 1, next_sync_committee_period);
 
 	new_tester().execute_with(|| {
@@ -449,71 +773,1517 @@ fn submit_update_with_missing_bootstrap() {
 	});
 }
 
+/// Strips the finality proof from an update. The update remains validly signed, since the
+/// signature only covers the attested header.
+fn without_finality(mut update: Update) -> Update {
+	update.finalized_header = BeaconHeader::default();
+	update.finality_branch = vec![H256::zero(); update.finality_branch.len()];
+	update.block_roots_root = H256::zero();
+	update.block_roots_branch = vec![];
+	update
+}
+
 #[test]
-fn submit_update_with_invalid_sync_committee_update() {
+fn submit_update_without_finality() {
 	let checkpoint = load_checkpoint_update_fixture();
-	let update = load_sync_committee_update_fixture();
-	let mut next_update = load_next_sync_committee_update_fixture();
+	let update = without_finality(load_finalized_header_update_fixture());
 
 	new_tester().execute_with(|| {
 		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		let finalized_block_root = <LatestFinalizedBlockRoot<Test>>::get();
 
-		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), update.clone()));
+		let result = EthereumBeaconClient::submit(RuntimeOrigin::signed(1), update.clone());
+		assert_ok!(result);
+		// The update is kept as the best valid update, without advancing the light client.
+		assert_eq!(result.unwrap().pays_fee, Pays::Yes);
+		assert_eq!(<LatestFinalizedBlockRoot<Test>>::get(), finalized_block_root);
+		assert_eq!(EthereumBeaconClient::best_valid_update(), Some((update, 0)));
+	});
+}
 
-		// makes update with invalid next_sync_committee
-		<FinalizedBeaconState<Test>>::mutate(<LatestFinalizedBlockRoot<Test>>::get(), |x| {
-			let prev = x.unwrap();
-			*x = Some(CompactBeaconState { slot: next_update.attested_header.slot, ..prev });
-		});
-		next_update.attested_header.slot = next_update.attested_header.slot + 1;
-		next_update.signature_slot = next_update.attested_header.slot + 1;
-		let next_sync_committee = NextSyncCommitteeUpdate::default();
-		next_update.next_sync_committee_update = Some(next_sync_committee);
+#[test]
+fn submit_update_without_finality_with_finality_branch() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let mut update = load_finalized_header_update_fixture();
+	update.finalized_header = BeaconHeader::default();
 
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
 		assert_err!(
-			EthereumBeaconClient::submit(RuntimeOrigin::signed(1), next_update.clone()),
-			Error::<Test>::InvalidSyncCommitteeUpdate
+			EthereumBeaconClient::submit(RuntimeOrigin::signed(1), update),
+			Error::<Test>::InvalidHeaderMerkleProof
 		);
 	});
 }
 
 #[test]
-fn submit_execution_header_update() {
+fn submit_finalized_update_clears_best_valid_update() {
 	let checkpoint = load_checkpoint_update_fixture();
-	let finalized_header_update = load_finalized_header_update_fixture();
-	let execution_header_update = load_execution_header_update_fixture();
+	let update = load_finalized_header_update_fixture();
 
 	new_tester().execute_with(|| {
 		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
-		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), finalized_header_update));
-		assert_ok!(EthereumBeaconClient::submit_execution_header(
+		assert_ok!(EthereumBeaconClient::submit(
 			RuntimeOrigin::signed(1),
-			execution_header_update.clone()
-		));
-		assert!(<ExecutionHeaders<Test>>::contains_key(
-			execution_header_update.execution_header.block_hash
+			without_finality(update.clone())
 		));
+		assert!(EthereumBeaconClient::best_valid_update().is_some());
+
+		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), update.clone()));
+		assert_eq!(EthereumBeaconClient::best_valid_update(), None);
+		let block_root: H256 = update.finalized_header.hash_tree_root().unwrap();
+		assert_eq!(<LatestFinalizedBlockRoot<Test>>::get(), block_root);
 	});
 }
 
 #[test]
-fn submit_execution_header_not_finalized() {
+fn submit_update_forces_best_valid_update_after_timeout() {
 	let checkpoint = load_checkpoint_update_fixture();
-	let finalized_header_update = load_finalized_header_update_fixture();
-	let update = load_execution_header_update_fixture();
+	let update = without_finality(load_finalized_header_update_fixture());
+	// The signature slot only selects the fork version of the signature.
+	let later_update = Update { signature_slot: update.signature_slot + 1, ..update.clone() };
 
 	new_tester().execute_with(|| {
+		System::set_block_number(1);
 		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
-		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), finalized_header_update));
+		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), update.clone()));
+		assert_eq!(EthereumBeaconClient::best_valid_update(), Some((update.clone(), 1)));
 
-		<FinalizedBeaconState<Test>>::mutate(<LatestFinalizedBlockRoot<Test>>::get(), |x| {
-			let prev = x.unwrap();
-			*x = Some(CompactBeaconState { slot: update.header.slot - 1, ..prev });
-		});
+		// The timeout is measured in blocks, so updates with later slots do not force the best
+		// valid update, nor restart the timeout.
+		System::set_block_number(1 + UpdateTimeout::get());
+		let result = EthereumBeaconClient::submit(RuntimeOrigin::signed(1), later_update.clone());
+		assert_ok!(result);
+		assert_eq!(result.unwrap().pays_fee, Pays::Yes);
+		assert_eq!(EthereumBeaconClient::best_valid_update(), Some((update.clone(), 1)));
+
+		// The timeout has passed, so the attested header of the best valid update is imported
+		// as finalized.
+		System::set_block_number(2 + UpdateTimeout::get());
+		let result = EthereumBeaconClient::submit(RuntimeOrigin::signed(1), later_update);
+		assert_ok!(result);
+		assert_eq!(result.unwrap().pays_fee, Pays::No);
+		assert_eq!(EthereumBeaconClient::best_valid_update(), None);
+
+		let block_root: H256 = update.attested_header.hash_tree_root().unwrap();
+		assert_eq!(<LatestFinalizedBlockRoot<Test>>::get(), block_root);
+		assert_eq!(
+			<FinalizedBeaconState<Test>>::get(block_root),
+			Some(CompactBeaconState {
+				slot: update.attested_header.slot,
+				block_roots_root: H256::zero()
+			})
+		);
+		// The forced header is not finalized, so it is not recorded by slot.
+		assert_eq!(<FinalizedBlockRoots<Test>>::get(update.attested_header.slot), None);
+		System::assert_has_event(RuntimeEvent::EthereumBeaconClient(crate::Event::UpdateForced {
+			slot: update.attested_header.slot,
+		}));
+	});
+}
+
+#[test]
+fn submit_update_drops_best_valid_update_which_cannot_be_applied() {
+	let checkpoint = load_checkpoint_update_fixture();
+	// Without the next sync committee of the checkpoint period, the update carrying the sync
+	// committee of the following period cannot be applied.
+	let best_valid_update = load_next_sync_committee_update_fixture();
+	let update = without_finality(load_finalized_header_update_fixture());
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		let finalized_block_root = <LatestFinalizedBlockRoot<Test>>::get();
+		<BestValidUpdate<Test>>::set(Some((best_valid_update, 0)));
+
+		System::set_block_number(1 + UpdateTimeout::get());
+		let result = EthereumBeaconClient::submit(RuntimeOrigin::signed(1), update);
+		assert_ok!(result);
+		assert_eq!(result.unwrap().pays_fee, Pays::Yes);
+		assert_eq!(EthereumBeaconClient::best_valid_update(), None);
+		assert_eq!(<LatestFinalizedBlockRoot<Test>>::get(), finalized_block_root);
+	});
+}
+
+#[test]
+fn compare_valid_updates() {
+	let update = load_finalized_header_update_fixture();
+	let update_without_finality = without_finality(update.clone());
+
+	let mut fewer_participants = update.clone();
+	let bits = &mut fewer_participants.sync_aggregate.sync_committee_bits;
+	let index = bits.iter().position(|byte| *byte != 0).unwrap();
+	bits[index] &= bits[index] - 1;
 
+	let mut newer = update.clone();
+	newer.attested_header.slot += 1;
+
+	new_tester().execute_with(|| {
+		// Finality is preferred
+		assert!(EthereumBeaconClient::is_better_update(&update, &update_without_finality));
+		assert!(!EthereumBeaconClient::is_better_update(&update_without_finality, &update));
+		// Then participation
+		assert!(EthereumBeaconClient::is_better_update(&update, &fewer_participants));
+		assert!(!EthereumBeaconClient::is_better_update(&fewer_participants, &update));
+		// Then older data
+		assert!(EthereumBeaconClient::is_better_update(&update, &newer));
+		assert!(!EthereumBeaconClient::is_better_update(&newer, &update));
+		assert!(!EthereumBeaconClient::is_better_update(&update, &update));
+	});
+}
+
+#[test]
+fn process_checkpoint_with_invalid_sync_committee_size() {
+	let mut checkpoint = load_checkpoint_update_fixture();
+	checkpoint.current_sync_committee.pubkeys.pop();
+
+	new_tester().execute_with(|| {
 		assert_err!(
-			EthereumBeaconClient::submit_execution_header(RuntimeOrigin::signed(1), update.clone()),
-			Error::<Test>::HeaderNotFinalized
+			EthereumBeaconClient::process_checkpoint_update(&checkpoint),
+			Error::<Test>::InvalidSyncCommitteeSize
+		);
+	});
+}
+
+/// Adds the next sync committee to the checkpoint. The next sync committee is the sibling of the
+/// current sync committee in the beacon state, whose root is recomputed accordingly.
+fn checkpoint_with_next_sync_committee(
+	mut checkpoint: CheckpointUpdate,
+	next_sync_committee: SyncCommittee,
+) -> CheckpointUpdate {
+	let slot = checkpoint.header.slot;
+	let gindex = EthereumBeaconClient::current_sync_committee_gindex_at_slot(slot);
+	assert_eq!(EthereumBeaconClient::next_sync_committee_gindex_at_slot(slot), gindex + 1);
+
+	let current_root = checkpoint.current_sync_committee.hash_tree_root().unwrap();
+	let mut next_sync_committee_branch = checkpoint.current_sync_committee_branch.clone();
+	next_sync_committee_branch[0] = current_root;
+	let branch = &mut checkpoint.current_sync_committee_branch;
+	branch[0] = next_sync_committee.hash_tree_root().unwrap();
+
+	// The sync committees are in the right half of the beacon state and the block roots in the
+	// left half, so the block roots branch ends with the root of the right half.
+	let right_half_root =
+		compute_merkle_root(current_root, &branch[..branch.len() - 1], subtree_index(gindex));
+	checkpoint.header.state_root = compute_merkle_root(current_root, branch, subtree_index(gindex));
+	*checkpoint.block_roots_branch.last_mut().unwrap() = right_half_root;
+
+	checkpoint.next_sync_committee_update =
+		Some(NextSyncCommitteeUpdate { next_sync_committee, next_sync_committee_branch });
+	checkpoint
+}
+
+#[test]
+fn process_checkpoint_with_next_sync_committee() {
+	let next_sync_committee = load_sync_committee_update_fixture()
+		.next_sync_committee_update
+		.unwrap()
+		.next_sync_committee;
+	let checkpoint = checkpoint_with_next_sync_committee(
+		load_checkpoint_update_fixture(),
+		next_sync_committee.clone(),
+	);
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		assert_eq!(
+			<NextSyncCommittee<Test>>::get().root,
+			next_sync_committee.hash_tree_root().unwrap()
+		);
+	});
+}
+
+#[test]
+fn process_checkpoint_with_invalid_next_sync_committee_proof() {
+	let next_sync_committee = load_sync_committee_update_fixture()
+		.next_sync_committee_update
+		.unwrap()
+		.next_sync_committee;
+	let mut checkpoint =
+		checkpoint_with_next_sync_committee(load_checkpoint_update_fixture(), next_sync_committee);
+	checkpoint
+		.next_sync_committee_update
+		.as_mut()
+		.unwrap()
+		.next_sync_committee_branch[0] = H256::zero();
+
+	new_tester().execute_with(|| {
+		assert_err!(
+			EthereumBeaconClient::process_checkpoint_update(&checkpoint),
+			Error::<Test>::InvalidSyncCommitteeMerkleProof
+		);
+		assert!(!<NextSyncCommittee<Test>>::exists());
+	});
+}
+
+#[test]
+fn process_checkpoint_with_invalid_next_sync_committee_pubkey() {
+	let mut next_sync_committee = load_sync_committee_update_fixture()
+		.next_sync_committee_update
+		.unwrap()
+		.next_sync_committee;
+	// The point at infinity is well formed, but rejected by KeyValidate
+	let mut infinity = [0u8; 48];
+	infinity[0] = 0xc0;
+	next_sync_committee.pubkeys[1] = PublicKey(infinity);
+	let checkpoint =
+		checkpoint_with_next_sync_committee(load_checkpoint_update_fixture(), next_sync_committee);
+
+	new_tester().execute_with(|| {
+		assert_err!(
+			EthereumBeaconClient::process_checkpoint_update(&checkpoint),
+			Error::<Test>::BLSPreparePublicKeysFailed
+		);
+		assert!(!<NextSyncCommittee<Test>>::exists());
+	});
+}
+
+#[test]
+fn process_stale_checkpoint() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let set_finalized_slot = |slot: u64| {
+		<FinalizedBeaconState<Test>>::mutate(<LatestFinalizedBlockRoot<Test>>::get(), |state| {
+			state.as_mut().unwrap().slot = slot
+		});
+	};
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+
+		// The checkpoint is within the weak subjectivity period of the latest finalized header
+		set_finalized_slot(checkpoint.header.slot + WeakSubjectivityPeriod::get());
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+
+		set_finalized_slot(checkpoint.header.slot + WeakSubjectivityPeriod::get() + 1);
+		assert_err!(
+			EthereumBeaconClient::process_checkpoint_update(&checkpoint),
+			Error::<Test>::StaleCheckpoint
+		);
+	});
+}
+
+#[test]
+fn submit_update_with_invalid_sync_committee_bits_size() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let mut update = load_finalized_header_update_fixture();
+	update.sync_aggregate.sync_committee_bits.try_push(0xff).unwrap();
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		assert_err!(
+			EthereumBeaconClient::submit(RuntimeOrigin::signed(1), update),
+			Error::<Test>::InvalidSyncCommitteeSize
+		);
+	});
+}
+
+#[test]
+fn submit_update_with_invalid_sync_committee_update() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let update = load_sync_committee_update_fixture();
+	let mut next_update = load_next_sync_committee_update_fixture();
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+
+		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), update.clone()));
+
+		// makes update with invalid next_sync_committee
+		<FinalizedBeaconState<Test>>::mutate(<LatestFinalizedBlockRoot<Test>>::get(), |x| {
+			let prev = x.unwrap();
+			*x = Some(CompactBeaconState { slot: next_update.attested_header.slot, ..prev });
+		});
+		next_update.attested_header.slot = next_update.attested_header.slot + 1;
+		next_update.signature_slot = next_update.attested_header.slot + 1;
+		let next_sync_committee = NextSyncCommitteeUpdate::default();
+		next_update.next_sync_committee_update = Some(next_sync_committee);
+
+		assert_err!(
+			EthereumBeaconClient::submit(RuntimeOrigin::signed(1), next_update.clone()),
+			Error::<Test>::InvalidSyncCommitteeUpdate
+		);
+	});
+}
+
+#[test]
+fn submit_execution_header_update() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let finalized_header_update = load_finalized_header_update_fixture();
+	let execution_header_update = load_execution_header_update_fixture();
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), finalized_header_update));
+		assert_ok!(EthereumBeaconClient::submit_execution_header(
+			RuntimeOrigin::signed(1),
+			execution_header_update.clone()
+		));
+		assert!(<ExecutionHeaders<Test>>::contains_key(
+			execution_header_update.execution_header.block_hash()
+		));
+	});
+}
+
+#[test]
+fn submit_execution_header_not_finalized() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let finalized_header_update = load_finalized_header_update_fixture();
+	let update = load_execution_header_update_fixture();
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), finalized_header_update));
+
+		<FinalizedBeaconState<Test>>::mutate(<LatestFinalizedBlockRoot<Test>>::get(), |x| {
+			let prev = x.unwrap();
+			*x = Some(CompactBeaconState { slot: update.header.slot - 1, ..prev });
+		});
+
+		assert_err!(
+			EthereumBeaconClient::submit_execution_header(RuntimeOrigin::signed(1), update.clone()),
+			Error::<Test>::HeaderNotFinalized
+		);
+	});
+}
+
+#[test]
+fn submit_execution_header_update_deneb() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let update = load_execution_header_update_deneb_fixture();
+	let finalized_block_root = match update.ancestry_proof.as_ref().unwrap() {
+		AncestryProof::BlockRoots { finalized_block_root, .. } => *finalized_block_root,
+		_ => unreachable!("fixture is proven through block roots"),
+	};
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		// The fixture is proven against a finalized header in the Deneb fork, which is stored
+		// directly rather than through a signed update.
+		<FinalizedBeaconState<Test>>::insert(
+			finalized_block_root,
+			CompactBeaconState {
+				slot: update.header.slot + 16,
+				block_roots_root: hex!(
+					"840dc070a6ce732c5ed4ab2d571b787fe2ecb0be65613ccaa4499c04f9c200c8"
+				)
+				.into(),
+			},
+		);
+		<FinalizedBlockRoots<Test>>::insert(update.header.slot + 16, finalized_block_root);
+		<LatestFinalizedBlockRoot<Test>>::set(finalized_block_root);
+
+		assert_ok!(EthereumBeaconClient::submit_execution_header(
+			RuntimeOrigin::signed(1),
+			update.clone()
+		));
+		// The transactions root is retained, since the block hash commits to it.
+		let header = <ExecutionHeaders<Test>>::get(update.execution_header.block_hash()).unwrap();
+		assert_eq!(
+			header.transactions_root,
+			Some(update.execution_block_roots.transactions_root)
+		);
+	});
+}
+
+#[test]
+fn submit_execution_header_update_against_forced_header() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let update = load_execution_header_update_deneb_fixture();
+	let finalized_block_root = match update.ancestry_proof.as_ref().unwrap() {
+		AncestryProof::BlockRoots { finalized_block_root, .. } => *finalized_block_root,
+		_ => unreachable!("fixture is proven through block roots"),
+	};
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		// A header imported by a forced update is stored without being recorded by slot.
+		<FinalizedBeaconState<Test>>::insert(
+			finalized_block_root,
+			CompactBeaconState {
+				slot: update.header.slot + 16,
+				block_roots_root: hex!(
+					"840dc070a6ce732c5ed4ab2d571b787fe2ecb0be65613ccaa4499c04f9c200c8"
+				)
+				.into(),
+			},
+		);
+		<LatestFinalizedBlockRoot<Test>>::set(finalized_block_root);
+
+		assert_err!(
+			EthereumBeaconClient::submit_execution_header(RuntimeOrigin::signed(1), update),
+			Error::<Test>::FinalizedHeaderForced
+		);
+	});
+}
+
+#[test]
+fn submit_execution_header_update_sparse() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let finalized_header_update = load_finalized_header_update_fixture();
+	let capella_update = load_execution_header_update_fixture();
+	let deneb_update = load_execution_header_update_deneb_fixture();
+	let deneb_finalized_block_root = match deneb_update.ancestry_proof.as_ref().unwrap() {
+		AncestryProof::BlockRoots { finalized_block_root, .. } => *finalized_block_root,
+		_ => unreachable!("fixture is proven through block roots"),
+	};
+	assert!(
+		deneb_update.execution_header.block_number() + 1 <
+			capella_update.execution_header.block_number()
+	);
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), finalized_header_update));
+		<FinalizedBeaconState<Test>>::insert(
+			deneb_finalized_block_root,
+			CompactBeaconState {
+				slot: deneb_update.header.slot + 16,
+				block_roots_root: hex!(
+					"840dc070a6ce732c5ed4ab2d571b787fe2ecb0be65613ccaa4499c04f9c200c8"
+				)
+				.into(),
+			},
+		);
+		<FinalizedBlockRoots<Test>>::insert(
+			deneb_update.header.slot + 16,
+			deneb_finalized_block_root,
+		);
+		<LatestFinalizedBlockRoot<Test>>::set(deneb_finalized_block_root);
+
+		// Execution headers do not need to be imported sequentially, nor in order, but only
+		// headers newer than the latest imported one are free of charge.
+		System::set_block_number(1);
+		let result = EthereumBeaconClient::submit_execution_header(
+			RuntimeOrigin::signed(1),
+			capella_update.clone(),
+		);
+		assert_eq!(result.unwrap().pays_fee, Pays::No);
+		let result = EthereumBeaconClient::submit_execution_header(
+			RuntimeOrigin::signed(1),
+			deneb_update.clone(),
+		);
+		assert_eq!(result.unwrap().pays_fee, Pays::Yes);
+		assert_eq!(<FreeUpdatesInBlock<Test>>::get(), (1, 1));
+		assert!(<ExecutionHeaders<Test>>::contains_key(
+			capella_update.execution_header.block_hash()
+		));
+		assert!(<ExecutionHeaders<Test>>::contains_key(deneb_update.execution_header.block_hash()));
+
+		// The latest execution state tracks the highest imported block number.
+		let latest_execution_state = EthereumBeaconClient::latest_execution_state();
+		assert_eq!(
+			latest_execution_state.block_number,
+			capella_update.execution_header.block_number()
+		);
+		assert_eq!(latest_execution_state.block_hash, capella_update.execution_header.block_hash());
+	});
+}
+
+#[test]
+fn submit_execution_header_update_already_imported() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let finalized_header_update = load_finalized_header_update_fixture();
+	let update = load_execution_header_update_fixture();
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), finalized_header_update));
+		assert_ok!(EthereumBeaconClient::submit_execution_header(
+			RuntimeOrigin::signed(1),
+			update.clone()
+		));
+		assert_noop!(
+			EthereumBeaconClient::submit_execution_header(RuntimeOrigin::signed(1), update),
+			Error::<Test>::ExecutionHeaderAlreadyImported
+		);
+	});
+}
+
+#[test]
+fn submit_execution_header_update_historical_summaries() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let update = load_execution_header_update_historical_summaries_fixture();
+	let finalized_header = match update.ancestry_proof.as_ref().unwrap() {
+		AncestryProof::HistoricalSummaries { finalized_header, .. } => finalized_header.clone(),
+		_ => unreachable!("fixture is proven through historical summaries"),
+	};
+	let finalized_block_root: H256 = finalized_header.hash_tree_root().unwrap();
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		// The header is older than the `block_roots` of the finalized header, which is stored
+		// directly rather than through a signed update.
+		<FinalizedBeaconState<Test>>::insert(
+			finalized_block_root,
+			CompactBeaconState { slot: finalized_header.slot, block_roots_root: H256::random() },
+		);
+		<FinalizedBlockRoots<Test>>::insert(finalized_header.slot, finalized_block_root);
+		<LatestFinalizedBlockRoot<Test>>::set(finalized_block_root);
+
+		assert_ok!(EthereumBeaconClient::submit_execution_header(
+			RuntimeOrigin::signed(1),
+			update.clone()
+		));
+		assert!(<ExecutionHeaders<Test>>::contains_key(update.execution_header.block_hash()));
+	});
+}
+
+#[test]
+fn submit_execution_header_update_invalid_historical_summaries_proof() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let mut update = load_execution_header_update_historical_summaries_fixture();
+	let finalized_header = match update.ancestry_proof.as_mut().unwrap() {
+		AncestryProof::HistoricalSummaries { finalized_header, block_summary_root, .. } => {
+			*block_summary_root = H256::random();
+			finalized_header.clone()
+		},
+		_ => unreachable!("fixture is proven through historical summaries"),
+	};
+	let finalized_block_root: H256 = finalized_header.hash_tree_root().unwrap();
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		<FinalizedBeaconState<Test>>::insert(
+			finalized_block_root,
+			CompactBeaconState { slot: finalized_header.slot, block_roots_root: H256::random() },
+		);
+		<FinalizedBlockRoots<Test>>::insert(finalized_header.slot, finalized_block_root);
+		<LatestFinalizedBlockRoot<Test>>::set(finalized_block_root);
+
+		assert_err!(
+			EthereumBeaconClient::submit_execution_header(RuntimeOrigin::signed(1), update),
+			Error::<Test>::InvalidAncestryMerkleProof
+		);
+	});
+}
+
+#[test]
+fn submit_execution_header_update_historical_summary_not_available() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let mut update = load_execution_header_update_historical_summaries_fixture();
+	let finalized_header = match update.ancestry_proof.as_mut().unwrap() {
+		AncestryProof::HistoricalSummaries { finalized_header, .. } => {
+			// The historical summary covering the header is only appended once the
+			// `SLOTS_PER_HISTORICAL_ROOT` period of the header has ended.
+			finalized_header.slot = update.header.slot + 1;
+			finalized_header.clone()
+		},
+		_ => unreachable!("fixture is proven through historical summaries"),
+	};
+	let finalized_block_root: H256 = finalized_header.hash_tree_root().unwrap();
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		<FinalizedBeaconState<Test>>::insert(
+			finalized_block_root,
+			CompactBeaconState { slot: finalized_header.slot, block_roots_root: H256::random() },
+		);
+		<FinalizedBlockRoots<Test>>::insert(finalized_header.slot, finalized_block_root);
+		<LatestFinalizedBlockRoot<Test>>::set(finalized_block_root);
+
+		assert_err!(
+			EthereumBeaconClient::submit_execution_header(RuntimeOrigin::signed(1), update),
+			Error::<Test>::HistoricalSummaryNotAvailable
+		);
+	});
+}
+
+#[test]
+fn submit_execution_header_update_with_wrong_version() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let finalized_header_update = load_finalized_header_update_fixture();
+	let mut capella_update = load_execution_header_update_fixture();
+	let mut deneb_update = load_execution_header_update_deneb_fixture();
+
+	// Swap the execution header versions, so that each header is versioned for the wrong fork.
+	let capella_execution_header = capella_update.execution_header.clone();
+	capella_update.execution_header = deneb_update.execution_header.clone();
+	deneb_update.execution_header = capella_execution_header;
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), finalized_header_update));
+		assert_err!(
+			EthereumBeaconClient::submit_execution_header(
+				RuntimeOrigin::signed(1),
+				capella_update.clone()
+			),
+			Error::<Test>::InvalidExecutionHeaderVersion
+		);
+
+		<FinalizedBeaconState<Test>>::mutate(<LatestFinalizedBlockRoot<Test>>::get(), |x| {
+			let prev = x.unwrap();
+			*x = Some(CompactBeaconState { slot: deneb_update.header.slot + 16, ..prev });
+		});
+		assert_err!(
+			EthereumBeaconClient::submit_execution_header(
+				RuntimeOrigin::signed(1),
+				deneb_update.clone()
+			),
+			Error::<Test>::InvalidExecutionHeaderVersion
+		);
+	});
+}
+
+#[test]
+fn submit_execution_header_update_with_invalid_block_hash() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let finalized_header_update = load_finalized_header_update_fixture();
+	let mut update = load_execution_header_update_fixture();
+	update.execution_block_roots.transactions_root = H256::random();
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), finalized_header_update));
+		assert_err!(
+			EthereumBeaconClient::submit_execution_header(RuntimeOrigin::signed(1), update),
+			Error::<Test>::InvalidExecutionBlockHash
+		);
+	});
+}
+
+#[test]
+fn submit_execution_header_update_with_unexpected_requests_hash() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let mut update = load_execution_header_update_deneb_fixture();
+	let finalized_block_root = match update.ancestry_proof.as_ref().unwrap() {
+		AncestryProof::BlockRoots { finalized_block_root, .. } => *finalized_block_root,
+		_ => unreachable!("fixture is proven through block roots"),
+	};
+	// The fixture is a Deneb block, whose block hash does not commit to a requests hash.
+	update.execution_block_roots.requests_hash = Some(H256::random());
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		<FinalizedBeaconState<Test>>::insert(
+			finalized_block_root,
+			CompactBeaconState {
+				slot: update.header.slot + 16,
+				block_roots_root: hex!(
+					"840dc070a6ce732c5ed4ab2d571b787fe2ecb0be65613ccaa4499c04f9c200c8"
+				)
+				.into(),
+			},
+		);
+		<FinalizedBlockRoots<Test>>::insert(update.header.slot + 16, finalized_block_root);
+		<LatestFinalizedBlockRoot<Test>>::set(finalized_block_root);
+
+		assert_err!(
+			EthereumBeaconClient::submit_execution_header(RuntimeOrigin::signed(1), update),
+			Error::<Test>::InvalidExecutionBlockHash
+		);
+	});
+}
+
+#[test]
+fn submit_execution_header_update_with_invalid_parent() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let finalized_header_update = load_finalized_header_update_fixture();
+	let update = load_execution_header_update_fixture();
+	let block_hash = update.execution_header.block_hash();
+
+	let child = |parent_hash: H256, block_number: u64| {
+		let mut child = update.clone();
+		match &mut child.execution_header {
+			VersionedExecutionPayloadHeader::Capella(payload) => {
+				payload.parent_hash = parent_hash;
+				payload.block_number = block_number;
+				payload.block_hash = H256::random();
+			},
+			VersionedExecutionPayloadHeader::Deneb(_) => unreachable!("fixture is in Capella"),
+		}
+		child
+	};
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), finalized_header_update));
+		assert_ok!(EthereumBeaconClient::submit_execution_header(
+			RuntimeOrigin::signed(1),
+			update.clone()
+		));
+
+		let block_number = update.execution_header.block_number();
+		// The next block must be a child of the latest imported block
+		assert_err!(
+			EthereumBeaconClient::submit_execution_header(
+				RuntimeOrigin::signed(1),
+				child(H256::random(), block_number + 1)
+			),
+			Error::<Test>::InvalidExecutionHeaderParent
+		);
+		// A child of an imported block must directly follow it
+		assert_err!(
+			EthereumBeaconClient::submit_execution_header(
+				RuntimeOrigin::signed(1),
+				child(block_hash, block_number + 2)
+			),
+			Error::<Test>::InvalidExecutionHeaderParent
+		);
+	});
+}
+
+#[test]
+fn set_operating_mode() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let update = load_finalized_header_update_fixture();
+	let execution_header_update = load_execution_header_update_fixture();
+
+	new_tester().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+
+		assert_ok!(EthereumBeaconClient::set_operating_mode(RuntimeOrigin::root(), Mode::Blocked));
+		System::assert_last_event(RuntimeEvent::EthereumBeaconClient(
+			crate::Event::OperatingModeChanged { mode: Mode::Blocked },
+		));
+
+		assert_noop!(
+			EthereumBeaconClient::submit(RuntimeOrigin::signed(1), update.clone()),
+			Error::<Test>::Halted
+		);
+		assert_noop!(
+			EthereumBeaconClient::submit_execution_header(
+				RuntimeOrigin::signed(1),
+				execution_header_update.clone()
+			),
+			Error::<Test>::Halted
+		);
+
+		assert_ok!(EthereumBeaconClient::set_operating_mode(RuntimeOrigin::root(), Mode::Active));
+		System::assert_last_event(RuntimeEvent::EthereumBeaconClient(
+			crate::Event::OperatingModeChanged { mode: Mode::Active },
+		));
+
+		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), update));
+		assert_ok!(EthereumBeaconClient::submit_execution_header(
+			RuntimeOrigin::signed(1),
+			execution_header_update
+		));
+	});
+}
+
+#[test]
+fn set_operating_mode_root_only() {
+	new_tester().execute_with(|| {
+		assert_noop!(
+			EthereumBeaconClient::set_operating_mode(RuntimeOrigin::signed(1), Mode::Blocked),
+			DispatchError::BadOrigin
+		);
+		assert_eq!(EthereumBeaconClient::operating_mode(), Mode::Active);
+	});
+}
+
+#[test]
+fn initial_checkpoint_from_genesis() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let update = load_finalized_header_update_fixture();
+	let config =
+		crate::GenesisConfig { fork_versions: None, initial_checkpoint: Some(checkpoint.clone()) };
+
+	new_tester_with_config(config).execute_with(|| {
+		let block_root: H256 = checkpoint.header.hash_tree_root().unwrap();
+		assert_eq!(EthereumBeaconClient::initial_checkpoint_root(), block_root);
+		assert_eq!(<LatestFinalizedBlockRoot<Test>>::get(), block_root);
+		assert_eq!(EthereumBeaconClient::validators_root(), checkpoint.validators_root);
+
+		// The light client is bootstrapped without a `force_checkpoint` call.
+		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), update));
+	});
+}
+
+#[test]
+#[should_panic(expected = "invalid initial checkpoint")]
+fn invalid_initial_checkpoint_from_genesis() {
+	let mut checkpoint = load_checkpoint_update_fixture();
+	checkpoint.current_sync_committee_branch[0] = H256::zero();
+	let config = crate::GenesisConfig { fork_versions: None, initial_checkpoint: Some(checkpoint) };
+
+	new_tester_with_config(config);
+}
+
+#[test]
+fn set_fork_schedule() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let mut fork_versions = ChainForkVersions::get();
+	// The Deneb fork is not active at the checkpoint yet, so it can be postponed.
+	fork_versions.deneb.epoch = 600;
+
+	new_tester().execute_with(|| {
+		assert_eq!(EthereumBeaconClient::fork_schedule(), ChainForkVersions::get());
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		assert_ok!(EthereumBeaconClient::set_fork_schedule(
+			RuntimeOrigin::root(),
+			fork_versions.clone()
+		));
+		assert_eq!(EthereumBeaconClient::fork_schedule(), fork_versions);
+		assert_eq!(EthereumBeaconClient::compute_fork_version(550), fork_versions.capella.version);
+	});
+}
+
+#[test]
+fn set_fork_schedule_root_only() {
+	new_tester().execute_with(|| {
+		assert_noop!(
+			EthereumBeaconClient::set_fork_schedule(
+				RuntimeOrigin::signed(1),
+				ChainForkVersions::get()
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_invalid_fork_schedule() {
+	let checkpoint = load_checkpoint_update_fixture();
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+
+		// The Capella fork is already active
+		let mut fork_versions = ChainForkVersions::get();
+		fork_versions.capella.version = [9, 9, 9, 9];
+		assert_noop!(
+			EthereumBeaconClient::set_fork_schedule(RuntimeOrigin::root(), fork_versions),
+			Error::<Test>::InvalidForkSchedule
+		);
+
+		// Upcoming forks cannot be scheduled before the latest finalized header
+		let mut fork_versions = ChainForkVersions::get();
+		fork_versions.deneb.epoch =
+			compute_epoch(checkpoint.header.slot, Minimal::SLOTS_PER_EPOCH as u64);
+		assert_noop!(
+			EthereumBeaconClient::set_fork_schedule(RuntimeOrigin::root(), fork_versions),
+			Error::<Test>::InvalidForkSchedule
+		);
+
+		// Forks must be scheduled in order
+		let mut fork_versions = ChainForkVersions::get();
+		fork_versions.deneb.epoch = fork_versions.electra.epoch + 1;
+		assert_noop!(
+			EthereumBeaconClient::set_fork_schedule(RuntimeOrigin::root(), fork_versions),
+			Error::<Test>::InvalidForkSchedule
+		);
+	});
+}
+
+#[test]
+fn fork_schedule_from_genesis() {
+	let mut fork_versions = ChainForkVersions::get();
+	fork_versions.electra.epoch = 2000;
+	let config = crate::GenesisConfig {
+		fork_versions: Some(fork_versions.clone()),
+		initial_checkpoint: None,
+	};
+
+	new_tester_with_config(config).execute_with(|| {
+		assert_eq!(EthereumBeaconClient::fork_schedule(), fork_versions);
+		assert!(!EthereumBeaconClient::is_electra(1000 * Minimal::SLOTS_PER_EPOCH as u64));
+	});
+}
+
+#[test]
+fn verify_message_when_halted() {
+	let message = Message {
+		proof: Proof {
+			block_hash: H256::random(),
+			tx_index: 0,
+			log_index: 0,
+			data: (vec![], vec![]),
+			execution_proof: None,
+		},
+	};
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::set_operating_mode(RuntimeOrigin::root(), Mode::Blocked));
+		assert_err!(EthereumBeaconClient::verify(&message), Error::<Test>::Halted);
+
+		assert_ok!(EthereumBeaconClient::set_operating_mode(RuntimeOrigin::root(), Mode::Active));
+		assert_err!(EthereumBeaconClient::verify(&message), Error::<Test>::MissingHeader);
+	});
+}
+
+#[test]
+fn submit_useful_update_is_free() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let update = load_finalized_header_update_fixture();
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+
+		let result = EthereumBeaconClient::submit(RuntimeOrigin::signed(1), update.clone());
+		assert_eq!(result.unwrap().pays_fee, Pays::No);
+
+		// The finalized header has already been imported, so resubmitting the update does not
+		// advance the light client.
+		let result = EthereumBeaconClient::submit(RuntimeOrigin::signed(1), update);
+		assert_eq!(result.unwrap().pays_fee, Pays::Yes);
+	});
+}
+
+#[test]
+fn free_updates_are_capped_per_block() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let sync_committee_update = load_sync_committee_update_fixture();
+	let finalized_header_update = load_finalized_header_update_fixture();
+	let execution_header_update = load_execution_header_update_fixture();
+	assert_eq!(MaxFreeUpdatesPerBlock::get(), 2);
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+
+		let result = EthereumBeaconClient::submit(RuntimeOrigin::signed(1), sync_committee_update);
+		assert_eq!(result.unwrap().pays_fee, Pays::No);
+		let result =
+			EthereumBeaconClient::submit(RuntimeOrigin::signed(1), finalized_header_update);
+		assert_eq!(result.unwrap().pays_fee, Pays::No);
+		let result = EthereumBeaconClient::submit_execution_header(
+			RuntimeOrigin::signed(1),
+			execution_header_update,
+		);
+		assert_eq!(result.unwrap().pays_fee, Pays::Yes);
+
+		assert_eq!(<FreeUpdatesInBlock<Test>>::get(), (0, 2));
+
+		// The allowance is restored by the first update of the next block.
+		System::set_block_number(1);
+		assert_eq!(EthereumBeaconClient::consume_free_update(), Pays::No);
+		assert_eq!(<FreeUpdatesInBlock<Test>>::get(), (1, 1));
+	});
+}
+
+#[test]
+fn runtime_api_verify_update_is_dry_run() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let update = load_sync_committee_update_fixture();
+	let mut invalid_update = update.clone();
+	invalid_update.finality_branch[0] = H256::zero();
+
+	new_tester().execute_with(|| {
+		assert_err!(crate::api::verify_update::<Test, ()>(&update), Error::<Test>::NotBootstrapped);
+
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		let checkpoint_root = crate::api::latest_finalized_block_root::<Test, ()>();
+		assert_eq!(
+			crate::api::current_sync_committee_period::<Test, ()>(),
+			Some(compute_period::<Minimal>(checkpoint.header.slot))
+		);
+		assert!(!crate::api::next_sync_committee_known::<Test, ()>());
+
+		assert_ok!(crate::api::verify_update::<Test, ()>(&update));
+		assert_err!(
+			crate::api::verify_update::<Test, ()>(&invalid_update),
+			Error::<Test>::InvalidHeaderMerkleProof
+		);
+		assert_eq!(crate::api::latest_finalized_block_root::<Test, ()>(), checkpoint_root);
+		assert!(!crate::api::next_sync_committee_known::<Test, ()>());
+
+		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), update.clone()));
+		assert!(crate::api::next_sync_committee_known::<Test, ()>());
+		assert_eq!(
+			crate::api::latest_finalized_beacon_state::<Test, ()>().map(|state| state.slot),
+			Some(update.finalized_header.slot)
+		);
+	});
+}
+
+#[test]
+fn verify_message_with_execution_proof() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let execution_proof = load_execution_header_update_deneb_fixture();
+	let block_hash = execution_proof.execution_header.block_hash();
+	let finalized_block_root = match execution_proof.ancestry_proof.as_ref().unwrap() {
+		AncestryProof::BlockRoots { finalized_block_root, .. } => *finalized_block_root,
+		_ => unreachable!("fixture is proven through block roots"),
+	};
+	let logs_bloom: [u8; 256] = match &execution_proof.execution_header {
+		VersionedExecutionPayloadHeader::Deneb(payload) =>
+			payload.logs_bloom.as_slice().try_into().unwrap(),
+		VersionedExecutionPayloadHeader::Capella(_) => unreachable!("fixture is in Deneb"),
+	};
+	// The only receipt in the fixture's execution block
+	let log = Log {
+		address: H160::repeat_byte(0x0c),
+		topics: vec![H256::repeat_byte(0x01)],
+		data: vec![1, 2, 3],
+	};
+	let (receipts_root, receipt_proof) = single_receipt_proof(&rlp::encode(&Receipt {
+		tx_type: 2,
+		post_state_or_status: vec![1],
+		cumulative_gas_used: 29000,
+		bloom: Bloom::from(&logs_bloom),
+		logs: vec![log.clone()],
+	}));
+	assert_eq!(receipts_root, execution_proof.execution_header.receipts_root());
+	let message = Message {
+		proof: Proof {
+			block_hash,
+			tx_index: 0,
+			log_index: 0,
+			data: (vec![], vec![receipt_proof]),
+			execution_proof: Some(execution_proof),
+		},
+	};
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		let checkpoint_root = <LatestFinalizedBlockRoot<Test>>::get();
+		// The execution header is proven against a finalized header in the Deneb fork, which is
+		// stored directly rather than through a signed update.
+		<FinalizedBeaconState<Test>>::insert(
+			finalized_block_root,
+			CompactBeaconState {
+				slot: message.proof.execution_proof.as_ref().unwrap().header.slot + 16,
+				block_roots_root: hex!(
+					"840dc070a6ce732c5ed4ab2d571b787fe2ecb0be65613ccaa4499c04f9c200c8"
+				)
+				.into(),
+			},
+		);
+		<FinalizedBlockRoots<Test>>::insert(
+			message.proof.execution_proof.as_ref().unwrap().header.slot + 16,
+			finalized_block_root,
+		);
+		<LatestFinalizedBlockRoot<Test>>::set(finalized_block_root);
+
+		// The execution header is proven inline, so the log is verified without the execution
+		// header being imported.
+		assert_eq!(EthereumBeaconClient::verify(&message), Ok(log));
+		assert_eq!(<ExecutionHeaders<Test>>::iter().count(), 0);
+
+		let mut invalid_message = message.clone();
+		invalid_message.proof.block_hash = H256::random();
+		assert_err!(EthereumBeaconClient::verify(&invalid_message), Error::<Test>::InvalidProof);
+
+		let mut invalid_message = message.clone();
+		invalid_message.proof.execution_proof.as_mut().unwrap().execution_branch[0] =
+			H256::random();
+		assert_err!(
+			EthereumBeaconClient::verify(&invalid_message),
+			Error::<Test>::InvalidExecutionHeaderProof
+		);
+
+		// The beacon header containing the execution header is not finalized yet.
+		<LatestFinalizedBlockRoot<Test>>::set(checkpoint_root);
+		assert_err!(EthereumBeaconClient::verify(&message), Error::<Test>::HeaderNotFinalized);
+		assert_eq!(<ExecutionHeaders<Test>>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn bls_host_function_verifies_sync_aggregate() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let update = load_sync_committee_update_fixture();
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		let sync_committee = <CurrentSyncCommittee<Test>>::get();
+		let sync_committee_bits =
+			decompress_sync_committee_bits(&update.sync_aggregate.sync_committee_bits);
+		let absent_pubkeys = EthereumBeaconClient::find_pubkeys(
+			&sync_committee_bits,
+			&sync_committee.pubkeys,
+			false,
+		);
+		let participant_pubkey =
+			EthereumBeaconClient::find_pubkeys(&sync_committee_bits, &sync_committee.pubkeys, true)
+				[0];
+		let signing_root = EthereumBeaconClient::signing_root(
+			&update.attested_header,
+			EthereumBeaconClient::validators_root(),
+			update.signature_slot,
+		)
+		.unwrap();
+		let signature = update.sync_aggregate.sync_committee_signature;
+		let verify = |absent_pubkeys: &[PublicKey], message: H256, signature: &Signature| {
+			fast_aggregate_verify_host(
+				&sync_committee.aggregate_pubkey,
+				absent_pubkeys,
+				message,
+				signature,
+			)
+		};
+
+		// The sync aggregate signed on chain
+		assert_ok!(verify(&absent_pubkeys, signing_root, &signature));
+		assert_err!(
+			verify(&absent_pubkeys, H256::random(), &signature),
+			BlsError::SignatureVerificationFailed
+		);
+		// A participant counted as absent
+		let mut more_absent_pubkeys = absent_pubkeys.clone();
+		more_absent_pubkeys.push(participant_pubkey);
+		assert_err!(
+			verify(&more_absent_pubkeys, signing_root, &signature),
+			BlsError::SignatureVerificationFailed
+		);
+
+		// The compressed point at infinity fails KeyValidate
+		let mut infinity = [0u8; 48];
+		infinity[0] = 0xc0;
+		assert_err!(
+			verify(&[PublicKey(infinity)], signing_root, &signature),
+			BlsError::InvalidPublicKey
+		);
+		// The infinity flag is set along with other bits
+		assert_err!(
+			verify(&[PublicKey([0xff; 48])], signing_root, &signature),
+			BlsError::InvalidPublicKey
+		);
+		assert_err!(
+			verify(&absent_pubkeys, signing_root, &Signature([0xff; 96])),
+			BlsError::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn bls_pubkey_validation_matches_across_backends() {
+	let checkpoint = load_checkpoint_update_fixture();
+	let signature = load_sync_committee_update_fixture().sync_aggregate.sync_committee_signature;
+	let sync_committee = &checkpoint.current_sync_committee;
+
+	let mut infinity = [0u8; 48];
+	infinity[0] = 0xc0;
+	// The point (0, 2) is on the curve, but not in the G1 subgroup
+	let mut not_in_subgroup = [0u8; 48];
+	not_in_subgroup[0] = 0xa0;
+	// x = 1 is not the x-coordinate of any point on the curve
+	let mut not_on_curve = [0u8; 48];
+	not_on_curve[0] = 0x80;
+	not_on_curve[47] = 0x01;
+
+	let pubkeys = [
+		(sync_committee.pubkeys[0], true),
+		(sync_committee.pubkeys[1], true),
+		(sync_committee.aggregate_pubkey, true),
+		(PublicKey(infinity), false),
+		(PublicKey([0xff; 48]), false),
+		(PublicKey(not_in_subgroup), false),
+		(PublicKey(not_on_curve), false),
+	];
+
+	new_tester().execute_with(|| {
+		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+		let aggregate_pubkey = <CurrentSyncCommittee<Test>>::get().aggregate_pubkey;
+
+		for (pubkey, valid) in pubkeys {
+			assert_eq!(primitives::bls::prepare_milagro_pubkey(&pubkey).is_ok(), valid);
+			// The public key is decompressed before the signature is checked
+			let native =
+				fast_aggregate_verify_host(&aggregate_pubkey, &[pubkey], H256::zero(), &signature);
+			assert_eq!(native != Err(BlsError::InvalidPublicKey), valid);
+		}
+	});
+}
+
+#[test]
+fn migrate_sync_committees_to_v2() {
+	use codec::Encode;
+
+	let checkpoint = load_checkpoint_update_fixture();
+	let sync_committee = &checkpoint.current_sync_committee;
+
+	new_tester().execute_with(|| {
+		StorageVersion::new(0).put::<EthereumBeaconClient>();
+		frame_support::storage::unhashed::put_raw(
+			&<CurrentSyncCommittee<Test>>::hashed_key(),
+			&sync_committee_v0(sync_committee).encode(),
+		);
+
+		<(
+			crate::migration::v1::MigrateMinimalToV1<Test>,
+			crate::migration::v2::MigrateToV2<Test>,
+		)>::on_runtime_upgrade();
+
+		assert_eq!(EthereumBeaconClient::on_chain_storage_version(), 2);
+		assert!(
+			<CurrentSyncCommittee<Test>>::get() ==
+				SyncCommitteePrepared::try_from(sync_committee).unwrap()
+		);
+		assert!(!<NextSyncCommittee<Test>>::exists());
+	});
+}
+
+#[test]
+fn migrate_execution_headers_to_v3() {
+	let block_hash = H256::repeat_byte(0x01);
+	let old = crate::migration::v3::CompactExecutionHeaderV2 {
+		parent_hash: H256::repeat_byte(0x02),
+		block_number: 5,
+		state_root: H256::repeat_byte(0x03),
+		receipts_root: H256::repeat_byte(0x04),
+	};
+
+	new_tester().execute_with(|| {
+		StorageVersion::new(2).put::<EthereumBeaconClient>();
+		frame_support::storage::unhashed::put(
+			&<ExecutionHeaders<Test>>::hashed_key_for(block_hash),
+			&old,
+		);
+
+		crate::migration::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+
+		assert_eq!(EthereumBeaconClient::on_chain_storage_version(), 3);
+		assert_eq!(
+			<ExecutionHeaders<Test>>::get(block_hash),
+			Some(CompactExecutionHeader {
+				parent_hash: old.parent_hash,
+				block_number: old.block_number,
+				state_root: old.state_root,
+				receipts_root: old.receipts_root,
+				transactions_root: None,
+			})
+		);
+	});
+}
+
+#[test]
+fn verify_account_and_storage() {
+	let block_hash = H256::repeat_byte(0x01);
+	let address = state_proof::CONTRACT_ADDRESS.into();
+	let account_proof = state_proof::account_proof();
+	let storage_proof = state_proof::storage_proof();
+	let slot = H256::from_low_u64_be(2);
+
+	new_tester().execute_with(|| {
+		assert_err!(
+			EthereumBeaconClient::verify_account(block_hash, address, &account_proof),
+			Error::<Test>::MissingHeader
+		);
+
+		EthereumBeaconClient::store_execution_header(
+			block_hash,
+			CompactExecutionHeader {
+				state_root: state_proof::STATE_ROOT.into(),
+				..Default::default()
+			},
+			0,
+			H256::zero(),
+		);
+
+		let account =
+			EthereumBeaconClient::verify_account(block_hash, address, &account_proof).unwrap();
+		assert_eq!(account.map(|account| account.nonce), Some(1));
+		assert_eq!(
+			EthereumBeaconClient::verify_storage(
+				block_hash,
+				address,
+				&account_proof,
+				slot,
+				&storage_proof
+			),
+			Ok((U256::one() << 200) + U256::from(7))
+		);
+		// Unset storage slots are zero
+		assert_eq!(
+			EthereumBeaconClient::verify_storage(
+				block_hash,
+				address,
+				&account_proof,
+				H256::from_low_u64_be(3),
+				&storage_proof[..1]
+			),
+			Ok(U256::zero())
+		);
+		assert_err!(
+			EthereumBeaconClient::verify_storage(
+				block_hash,
+				address,
+				&account_proof,
+				H256::from_low_u64_be(5),
+				&storage_proof
+			),
+			Error::<Test>::InvalidProof
+		);
+		// The proof shows that there is no account at the zero address
+		assert_eq!(
+			EthereumBeaconClient::verify_account(block_hash, H160::zero(), &account_proof[..1]),
+			Ok(None)
+		);
+	});
+}
+
+/// Builds a receipts trie containing a single receipt for the transaction at index 0, returning
+/// the receipts root and the proof, which consists of the root leaf node.
+fn single_receipt_proof(receipt: &[u8]) -> (H256, Vec<u8>) {
+	// The hex-prefix encoded path of the key `rlp(0u32)`, flagged as a leaf
+	let path = [0x20u8, 0x80];
+	let mut stream = rlp::RlpStream::new_list(2);
+	stream.append(&path.as_slice());
+	stream.append(&receipt);
+	let leaf = stream.out().to_vec();
+	(sp_io::hashing::keccak_256(&leaf).into(), leaf)
+}
+
+#[test]
+fn verify_message_from_failed_transaction() {
+	let log = Log {
+		address: H160::repeat_byte(0x0c),
+		topics: vec![H256::repeat_byte(0x01)],
+		data: vec![1, 2, 3],
+	};
+	let encode_receipt = |status: &[u8]| {
+		rlp::encode(&Receipt {
+			tx_type: 2,
+			post_state_or_status: status.to_vec(),
+			cumulative_gas_used: 21000,
+			bloom: Default::default(),
+			logs: vec![log.clone()],
+		})
+	};
+
+	let successful_block_hash = H256::repeat_byte(0x01);
+	let (successful_receipts_root, successful_proof) = single_receipt_proof(&encode_receipt(&[1]));
+	let failed_block_hash = H256::repeat_byte(0x02);
+	let (failed_receipts_root, failed_proof) = single_receipt_proof(&encode_receipt(&[]));
+
+	let message = |block_hash: H256, receipt_proof: Vec<u8>| Message {
+		proof: Proof {
+			block_hash,
+			tx_index: 0,
+			log_index: 0,
+			data: (vec![], vec![receipt_proof]),
+			execution_proof: None,
+		},
+	};
+
+	new_tester().execute_with(|| {
+		for (block_hash, receipts_root) in [
+			(successful_block_hash, successful_receipts_root),
+			(failed_block_hash, failed_receipts_root),
+		] {
+			EthereumBeaconClient::store_execution_header(
+				block_hash,
+				CompactExecutionHeader { receipts_root, ..Default::default() },
+				0,
+				H256::zero(),
+			);
+		}
+
+		let verified =
+			EthereumBeaconClient::verify(&message(successful_block_hash, successful_proof));
+		assert_eq!(verified, Ok(log.clone()));
+		assert_err!(
+			EthereumBeaconClient::verify(&message(failed_block_hash, failed_proof)),
+			Error::<Test>::TransactionFailed
+		);
+	});
+}
+
+#[test]
+fn verify_message_returns_log_at_index() {
+	let block_hash = H256::repeat_byte(0x01);
+	let logs: Vec<Log> = (0..2u8)
+		.map(|i| Log { address: H160::repeat_byte(i), topics: vec![], data: vec![i] })
+		.collect();
+	let receipt = Receipt {
+		tx_type: 3,
+		post_state_or_status: vec![1],
+		cumulative_gas_used: 21000,
+		bloom: Default::default(),
+		logs: logs.clone(),
+	};
+	let (receipts_root, receipt_proof) = single_receipt_proof(&rlp::encode(&receipt));
+
+	let message = |log_index: u32| Message {
+		proof: Proof {
+			block_hash,
+			tx_index: 0,
+			log_index,
+			data: (vec![], vec![receipt_proof.clone()]),
+			execution_proof: None,
+		},
+	};
+
+	new_tester().execute_with(|| {
+		EthereumBeaconClient::store_execution_header(
+			block_hash,
+			CompactExecutionHeader { receipts_root, ..Default::default() },
+			0,
+			H256::zero(),
+		);
+
+		assert_eq!(EthereumBeaconClient::verify(&message(0)), Ok(logs[0].clone()));
+		assert_eq!(EthereumBeaconClient::verify(&message(1)), Ok(logs[1].clone()));
+		assert_err!(EthereumBeaconClient::verify(&message(2)), Error::<Test>::InvalidProof);
+	});
+}
+
+// Transactions trie proof of an EIP-4844 transaction at index 3, sent by
+// 9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f
+const TRANSACTIONS_ROOT: [u8; 32] =
+	hex!("d44d1805243d1b5951ec397067df01f2fc33258f72da9ecb310a9f604d499cd1");
+const TRANSACTION_PROOF_0: [u8; 83] = hex!(
+	"
+	f851a06892d48064007d87ebcc099a998baafade741a34367a1896e63e177ca102782e8080808080
+	8080a04260f4096239188752409be77ebd181d2dfc03725d34ef849e5b4bae1308838a8080808080
+	808080
+"
+);
+const TRANSACTION_PROOF_1: [u8; 147] = hex!(
+	"
+	f89180a08abb25e4eacf2838c8603fd3ca27e6527ba95e00350c45b9427ccab86a82d897a0748312
+	8cddf7e212ad53b27710994686c900ee9c770135950f2dbdf9e15a4b57a044af9a84893d81a33266
+	01ca657e3a26b9f55e0c4a125375d2642214b4777332a0a590c5fab15eaad3236c38d8ea22580776
+	97081b173d30b743690f5519ec33c1808080808080808080808080
+"
+);
+const TRANSACTION_PROOF_2: [u8; 152] = hex!(
+	"
+	f89620b89303f890010c843b9aca008506fc23ac0082c35094353535353535353535353535353535
+	353535353580820102c001e1a0010000000000000000000000000000000000000000000000000000
+	000000000080a0ec6d499aefd540e90357f1004a136049d1f7df5ad99c44c46e3ed4169e40acb6a0
+	11a063a20a1a3312a7d218d96331d8c92803a2d24c21f2ea2f5817d5bda19edb
+"
+);
+
+#[test]
+fn verify_transaction() {
+	let block_hash = H256::repeat_byte(0x01);
+	let other_block_hash = H256::repeat_byte(0x02);
+	let proof = vec![
+		TRANSACTION_PROOF_0.to_vec(),
+		TRANSACTION_PROOF_1.to_vec(),
+		TRANSACTION_PROOF_2.to_vec(),
+	];
+
+	new_tester().execute_with(|| {
+		assert_err!(
+			EthereumBeaconClient::verify_transaction(block_hash, 3, &proof),
+			Error::<Test>::MissingHeader
+		);
+
+		EthereumBeaconClient::store_execution_header(
+			block_hash,
+			CompactExecutionHeader {
+				transactions_root: Some(TRANSACTIONS_ROOT.into()),
+				..Default::default()
+			},
+			0,
+			H256::zero(),
+		);
+		// Headers imported from execution payload headers do not have a transactions root
+		EthereumBeaconClient::store_execution_header(
+			other_block_hash,
+			CompactExecutionHeader { transactions_root: None, ..Default::default() },
+			0,
+			H256::zero(),
+		);
+
+		let transaction = EthereumBeaconClient::verify_transaction(block_hash, 3, &proof).unwrap();
+		assert_eq!((transaction.tx_type, transaction.nonce), (3, 12));
+		assert_eq!(transaction.to, Some(H160::repeat_byte(0x35)));
+		assert_eq!(transaction.input, hex!("0102"));
+		assert_eq!(transaction.sender, hex!("9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f").into());
+
+		assert_err!(
+			EthereumBeaconClient::verify_transaction(block_hash, 2, &proof),
+			Error::<Test>::InvalidProof
+		);
+		assert_err!(
+			EthereumBeaconClient::verify_transaction(other_block_hash, 3, &proof),
+			Error::<Test>::MissingTransactionsRoot
 		);
 	});
 }
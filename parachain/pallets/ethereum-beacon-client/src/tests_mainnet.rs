@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+use crate::{
+	mock::mainnet::*, pallet::ExecutionHeaders, types::AncestryProof, CompactBeaconState, Error,
+	FinalizedBeaconState, FinalizedBlockRoots, LatestFinalizedBlockRoot,
+};
+
+use frame_support::{assert_err, assert_ok};
+use hex_literal::hex;
+
+#[test]
+fn submit_execution_header_update_deneb() {
+	let update = load_execution_header_update_deneb_fixture();
+	let finalized_block_root = match update.ancestry_proof.as_ref().unwrap() {
+		AncestryProof::BlockRoots { finalized_block_root, .. } => *finalized_block_root,
+		_ => unreachable!("fixture is proven through block roots"),
+	};
+
+	new_tester().execute_with(|| {
+		// The fixture is proven against a finalized header in the Deneb fork, which is stored
+		// directly rather than through a signed update.
+		<FinalizedBeaconState<Test>>::insert(
+			finalized_block_root,
+			CompactBeaconState {
+				slot: update.header.slot + 32,
+				block_roots_root: hex!(
+					"b5404c9856bb39c4406e58345bdee3a899d0e05f77ef39d519a99a97664c562e"
+				)
+				.into(),
+			},
+		);
+		<FinalizedBlockRoots<Test>>::insert(update.header.slot + 32, finalized_block_root);
+		<LatestFinalizedBlockRoot<Test>>::set(finalized_block_root);
+
+		assert_ok!(EthereumBeaconClient::submit_execution_header(
+			RuntimeOrigin::signed(1),
+			update.clone()
+		));
+		assert!(<ExecutionHeaders<Test>>::contains_key(update.execution_header.block_hash()));
+	});
+}
+
+#[test]
+fn process_checkpoint_with_minimal_sync_committee() {
+	let checkpoint = crate::mock::minimal::load_checkpoint_update_fixture();
+
+	new_tester().execute_with(|| {
+		assert_err!(
+			EthereumBeaconClient::process_checkpoint_update(&checkpoint),
+			Error::<Test>::InvalidSyncCommitteeSize
+		);
+	});
+}
@@ -1,38 +1,39 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
-pub use crate::config::{
-	SLOTS_PER_HISTORICAL_ROOT, SYNC_COMMITTEE_BITS_SIZE as SC_BITS_SIZE,
-	SYNC_COMMITTEE_SIZE as SC_SIZE,
-};
 use frame_support::storage::types::OptionQuery;
 use snowbridge_core::RingBufferMapImpl;
 
-// Specialize types based on configured sync committee size
-pub type SyncCommittee = primitives::SyncCommittee<SC_SIZE>;
-pub type SyncCommitteePrepared = primitives::SyncCommitteePrepared<SC_SIZE>;
-pub type SyncAggregate = primitives::SyncAggregate<SC_SIZE, SC_BITS_SIZE>;
-pub type CheckpointUpdate = primitives::CheckpointUpdate<SC_SIZE>;
-pub type Update = primitives::Update<SC_SIZE, SC_BITS_SIZE>;
-pub type NextSyncCommitteeUpdate = primitives::NextSyncCommitteeUpdate<SC_SIZE>;
-
-pub use primitives::ExecutionHeaderUpdate;
+pub use primitives::{
+	AncestryProof, CheckpointUpdate, ExecutionHeaderUpdate, NextSyncCommitteeUpdate, SyncAggregate,
+	SyncCommittee, SyncCommitteePrepared, Update,
+};
 
 /// ExecutionHeader ring buffer implementation
-pub(crate) type ExecutionHeaderBuffer<T> = RingBufferMapImpl<
+pub(crate) type ExecutionHeaderBuffer<T, I = ()> = RingBufferMapImpl<
 	u32,
-	<T as crate::Config>::MaxExecutionHeadersToKeep,
-	crate::ExecutionHeaderIndex<T>,
-	crate::ExecutionHeaderMapping<T>,
-	crate::ExecutionHeaders<T>,
+	<T as crate::Config<I>>::MaxExecutionHeadersToKeep,
+	crate::ExecutionHeaderIndex<T, I>,
+	crate::ExecutionHeaderMapping<T, I>,
+	crate::ExecutionHeaders<T, I>,
 	OptionQuery,
 >;
 
 /// FinalizedState ring buffer implementation
-pub(crate) type FinalizedBeaconStateBuffer<T> = RingBufferMapImpl<
+pub(crate) type FinalizedBeaconStateBuffer<T, I = ()> = RingBufferMapImpl<
+	u32,
+	crate::MaxFinalizedHeadersToKeep<T, I>,
+	crate::FinalizedBeaconStateIndex<T, I>,
+	crate::FinalizedBeaconStateMapping<T, I>,
+	crate::FinalizedBeaconState<T, I>,
+	OptionQuery,
+>;
+
+/// Finalized block roots by slot ring buffer implementation
+pub(crate) type FinalizedBlockRootBuffer<T, I = ()> = RingBufferMapImpl<
 	u32,
-	crate::MaxFinalizedHeadersToKeep<T>,
-	crate::FinalizedBeaconStateIndex<T>,
-	crate::FinalizedBeaconStateMapping<T>,
-	crate::FinalizedBeaconState<T>,
+	crate::MaxFinalizedHeadersToKeep<T, I>,
+	crate::FinalizedBlockRootIndex<T, I>,
+	crate::FinalizedBlockRootMapping<T, I>,
+	crate::FinalizedBlockRoots<T, I>,
 	OptionQuery,
 >;
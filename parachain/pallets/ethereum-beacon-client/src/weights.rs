@@ -37,6 +37,9 @@ pub trait WeightInfo {
 	fn submit() -> Weight;
 	fn submit_with_sync_committee() -> Weight;
 	fn submit_execution_header() -> Weight;
+	fn force_update() -> Weight;
+	fn set_operating_mode() -> Weight;
+	fn set_fork_schedule() -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -49,15 +52,15 @@ impl WeightInfo for () {
 	}
 	fn submit() -> Weight {
 		Weight::from_parts(26_051_019_000 as u64, 0)
-			.saturating_add(Weight::from_parts(0, 93857))
-			.saturating_add(RocksDbWeight::get().reads(8))
-			.saturating_add(RocksDbWeight::get().writes(4))
+			.saturating_add(Weight::from_parts(0, 119425))
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(5))
 	}
 	fn submit_with_sync_committee() -> Weight {
 		Weight::from_parts(122_461_312_000 as u64, 0)
-			.saturating_add(Weight::from_parts(0, 93857))
-			.saturating_add(RocksDbWeight::get().reads(6))
-			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 119425))
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(2))
 	}
 	fn submit_execution_header() -> Weight {
 		Weight::from_parts(113_158_000 as u64, 0)
@@ -65,4 +68,20 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5))
 			.saturating_add(RocksDbWeight::get().writes(4))
 	}
+	fn force_update() -> Weight {
+		Weight::from_parts(97_263_571_000 as u64, 0)
+			.saturating_add(Weight::from_parts(0, 29069))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	fn set_operating_mode() -> Weight {
+		Weight::from_parts(9_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn set_fork_schedule() -> Weight {
+		Weight::from_parts(12_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(0, 3501))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
 }
@@ -18,7 +18,7 @@ use sp_runtime::{
 use sp_std::convert::From;
 
 use snowbridge_core::{Message, Proof};
-use snowbridge_ethereum::Log;
+use snowbridge_ethereum::{Log, Receipt};
 
 use hex_literal::hex;
 
@@ -89,13 +89,13 @@ impl pallet_balances::Config for Test {
 	type MaxHolds = ();
 }
 
-// Mock verifier
+// Mock verifier, which trusts the receipt in the proof without checking its inclusion
 pub struct MockVerifier;
 
 impl Verifier for MockVerifier {
 	fn verify(message: &Message) -> Result<Log, DispatchError> {
-		let log: Log = rlp::decode(&message.data).unwrap();
-		Ok(log)
+		let receipt: Receipt = rlp::decode(&message.proof.data.1[0]).unwrap();
+		Ok(receipt.logs[message.proof.log_index as usize].clone())
 	}
 }
 
@@ -168,6 +168,20 @@ const OUTBOUND_QUEUE_EVENT_LOG: [u8; 254] = hex!(
 
 use snowbridge_core::ParaId;
 
+// Builds a message for `log`, with a proof consisting of just the receipt containing it
+fn message_with_log(log: &[u8]) -> Message {
+	let receipt = Receipt { logs: vec![rlp::decode(log).unwrap()], ..Default::default() };
+	Message {
+		proof: Proof {
+			block_hash: Default::default(),
+			tx_index: Default::default(),
+			log_index: 0,
+			data: (vec![], vec![rlp::encode(&receipt).to_vec()]),
+			execution_proof: None,
+		},
+	}
+}
+
 #[test]
 fn test_submit() {
 	new_tester(OUTBOUND_QUEUE_ADDRESS.into()).execute_with(|| {
@@ -181,14 +195,7 @@ fn test_submit() {
 		let _ = Balances::mint_into(&sovereign_account, 10000);
 
 		// Submit message
-		let message = Message {
-			data: OUTBOUND_QUEUE_EVENT_LOG.into(),
-			proof: Proof {
-				block_hash: Default::default(),
-				tx_index: Default::default(),
-				data: Default::default(),
-			},
-		};
+		let message = message_with_log(&OUTBOUND_QUEUE_EVENT_LOG);
 		assert_ok!(InboundQueue::submit(origin.clone(), message.clone()));
 		expect_events(vec![InboundQueueEvent::MessageReceived {
 			dest: dest_para,
@@ -212,14 +219,7 @@ fn test_submit_with_invalid_outbound_queue() {
 		let _ = Balances::mint_into(&sovereign_account, 10000);
 
 		// Submit message
-		let message = Message {
-			data: OUTBOUND_QUEUE_EVENT_LOG.into(),
-			proof: Proof {
-				block_hash: Default::default(),
-				tx_index: Default::default(),
-				data: Default::default(),
-			},
-		};
+		let message = message_with_log(&OUTBOUND_QUEUE_EVENT_LOG);
 		assert_noop!(
 			InboundQueue::submit(origin.clone(), message.clone()),
 			Error::<Test>::InvalidOutboundQueue
@@ -239,14 +239,7 @@ fn test_submit_with_invalid_nonce() {
 		let _ = Balances::mint_into(&sovereign_account, 10000);
 
 		// Submit message
-		let message = Message {
-			data: OUTBOUND_QUEUE_EVENT_LOG.into(),
-			proof: Proof {
-				block_hash: Default::default(),
-				tx_index: Default::default(),
-				data: Default::default(),
-			},
-		};
+		let message = message_with_log(&OUTBOUND_QUEUE_EVENT_LOG);
 		assert_ok!(InboundQueue::submit(origin.clone(), message.clone()));
 
 		let event_dest = parse_dest(message.clone());
@@ -273,14 +266,7 @@ fn test_submit_no_funds_to_reward_relayers() {
 		assert_ok!(Balances::mint_into(&sovereign_account, 2));
 
 		// Submit message
-		let message = Message {
-			data: OUTBOUND_QUEUE_EVENT_LOG.into(),
-			proof: Proof {
-				block_hash: Default::default(),
-				tx_index: Default::default(),
-				data: Default::default(),
-			},
-		};
+		let message = message_with_log(&OUTBOUND_QUEUE_EVENT_LOG);
 		assert_noop!(
 			InboundQueue::submit(origin.clone(), message.clone()),
 			// should actually be `NoFunds`. See this bug in substrate:
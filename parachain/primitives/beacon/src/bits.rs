@@ -1,19 +1,10 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
-use sp_std::{convert::TryInto, prelude::*};
-use ssz_rs::{Bitvector, Deserialize};
+use sp_std::prelude::*;
 
-pub fn decompress_sync_committee_bits<
-	const SYNC_COMMITTEE_SIZE: usize,
-	const SYNC_COMMITTEE_BITS_SIZE: usize,
->(
-	input: [u8; SYNC_COMMITTEE_BITS_SIZE],
-) -> [u8; SYNC_COMMITTEE_SIZE] {
-	Bitvector::<{ SYNC_COMMITTEE_SIZE }>::deserialize(&input)
-		.expect("checked statically; qed")
-		.iter()
-		.map(|bit| u8::from(bit == true))
-		.collect::<Vec<u8>>()
-		.try_into()
-		.expect("checked statically; qed")
+/// Decompress packed bitvector into byte vector according to SSZ deserialization rules. Each byte
+/// in the decompressed vector is either 0 or 1. Bits are packed little-endian, so the first
+/// committee member is the least significant bit of the first byte.
+pub fn decompress_sync_committee_bits(input: &[u8]) -> Vec<u8> {
+	input.iter().flat_map(|byte| (0..8).map(move |bit| (byte >> bit) & 1)).collect()
 }
@@ -10,6 +10,7 @@ pub use milagro_bls::{
 use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::RuntimeDebug;
+use sp_runtime_interface::runtime_interface;
 use sp_std::prelude::*;
 
 #[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, TypeInfo, RuntimeDebug, PalletError)]
@@ -32,9 +33,141 @@ pub fn fast_aggregate_verify(
 	fast_aggregate_verify_pre_aggregated(agg_sig, agg_key, message)
 }
 
-/// Decompress one public key into a point in G1.
+/// fast_aggregate_verify through the `snowbridge_bls` host function, so that the absent public
+/// keys are decompressed and the pairing check runs natively rather than in Wasm.
+pub fn fast_aggregate_verify_host(
+	aggregate_pubkey: &PublicKeyPrepared,
+	absent_pubkeys: &[PublicKey],
+	message: H256,
+	signature: &Signature,
+) -> Result<(), BlsError> {
+	snowbridge_bls::fast_aggregate_verify(
+		&aggregate_pubkey.as_bytes(),
+		&absent_pubkeys.encode(),
+		message,
+		&signature.0,
+	)
+}
+
+/// Host functions for BLS signature verification. Nodes running a runtime that calls them must
+/// register `snowbridge_bls::HostFunctions`.
+#[runtime_interface]
+pub trait SnowbridgeBls {
+	/// Native fast_aggregate_verify, with the public keys and the signature compressed. The absent
+	/// public keys are SCALE encoded. All points are checked to be in their subgroup.
+	fn fast_aggregate_verify(
+		aggregate_pubkey: &[u8],
+		absent_pubkeys: &[u8],
+		message: H256,
+		signature: &[u8],
+	) -> Result<(), BlsError> {
+		let absent_pubkeys = Vec::<PublicKey>::decode(&mut &absent_pubkeys[..])
+			.map_err(|_| BlsError::InvalidPublicKey)?;
+		native::fast_aggregate_verify(aggregate_pubkey, &absent_pubkeys, message, signature)
+	}
+}
+
+/// Native BLS backend of the host functions, on top of blst.
+#[cfg(feature = "std")]
+mod native {
+	use super::*;
+	use crate::config::{PUBKEY_SIZE, SIGNATURE_SIZE};
+	use blst::{
+		blst_core_verify_pk_in_g1, blst_p1, blst_p1_add_or_double, blst_p1_affine,
+		blst_p1_affine_in_g1, blst_p1_affine_is_inf, blst_p1_cneg, blst_p1_from_affine,
+		blst_p1_to_affine, blst_p1_uncompress, blst_p2_affine, blst_p2_affine_in_g2,
+		blst_p2_uncompress, BLST_ERROR,
+	};
+
+	/// Domain separation tag of the proof of possession scheme used by the beacon chain.
+	const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+	/// Decompresses a public key, which must be a point of the G1 subgroup other than the
+	/// identity, as required by KeyValidate.
+	fn decompress_pubkey(pubkey: &[u8]) -> Result<blst_p1_affine, BlsError> {
+		ensure!(pubkey.len() == PUBKEY_SIZE, BlsError::InvalidPublicKey);
+		let mut point = blst_p1_affine::default();
+		// SAFETY: `pubkey` holds `PUBKEY_SIZE` bytes, the size of a compressed G1 point.
+		unsafe {
+			ensure!(
+				blst_p1_uncompress(&mut point, pubkey.as_ptr()) == BLST_ERROR::BLST_SUCCESS,
+				BlsError::InvalidPublicKey
+			);
+			ensure!(
+				blst_p1_affine_in_g1(&point) && !blst_p1_affine_is_inf(&point),
+				BlsError::InvalidPublicKey
+			);
+		}
+		Ok(point)
+	}
+
+	/// Decompresses a signature, which must be a point of the G2 subgroup.
+	fn decompress_signature(signature: &[u8]) -> Result<blst_p2_affine, BlsError> {
+		ensure!(signature.len() == SIGNATURE_SIZE, BlsError::InvalidSignature);
+		let mut point = blst_p2_affine::default();
+		// SAFETY: `signature` holds `SIGNATURE_SIZE` bytes, the size of a compressed G2 point.
+		unsafe {
+			ensure!(
+				blst_p2_uncompress(&mut point, signature.as_ptr()) == BLST_ERROR::BLST_SUCCESS,
+				BlsError::InvalidSignature
+			);
+			ensure!(blst_p2_affine_in_g2(&point), BlsError::InvalidSignature);
+		}
+		Ok(point)
+	}
+
+	/// fast_aggregate_verify with the aggregate key of the participants computed by subtracting
+	/// the absent public keys from the aggregate public key of the sync committee.
+	pub fn fast_aggregate_verify(
+		aggregate_pubkey: &[u8],
+		absent_pubkeys: &[PublicKey],
+		message: H256,
+		signature: &[u8],
+	) -> Result<(), BlsError> {
+		let signature = decompress_signature(signature)?;
+		let aggregate_pubkey = decompress_pubkey(aggregate_pubkey)?;
+
+		let mut aggregate = blst_p1::default();
+		let aggregate_ptr: *mut blst_p1 = &mut aggregate;
+		// SAFETY: all points are initialized, and blst allows the output to alias an input.
+		unsafe { blst_p1_from_affine(aggregate_ptr, &aggregate_pubkey) };
+		for pubkey in absent_pubkeys {
+			let absent_pubkey = decompress_pubkey(&pubkey.0)?;
+			let mut absent = blst_p1::default();
+			// SAFETY: as above.
+			unsafe {
+				blst_p1_from_affine(&mut absent, &absent_pubkey);
+				blst_p1_cneg(&mut absent, true);
+				blst_p1_add_or_double(aggregate_ptr, aggregate_ptr, &absent);
+			}
+		}
+
+		let message = message.as_bytes();
+		let mut participants_pubkey = blst_p1_affine::default();
+		// SAFETY: `message` and `DST` outlive the call, and no augmentation is passed.
+		let result = unsafe {
+			blst_p1_to_affine(&mut participants_pubkey, &aggregate);
+			blst_core_verify_pk_in_g1(
+				&participants_pubkey,
+				&signature,
+				true,
+				message.as_ptr(),
+				message.len(),
+				DST.as_ptr(),
+				DST.len(),
+				core::ptr::null(),
+				0,
+			)
+		};
+		ensure!(result == BLST_ERROR::BLST_SUCCESS, BlsError::SignatureVerificationFailed);
+		Ok(())
+	}
+}
+
+/// Decompress one public key into a point in G1. As in the native backend, the point must be in
+/// the G1 subgroup and other than the identity, as required by KeyValidate.
 pub fn prepare_milagro_pubkey(pubkey: &PublicKey) -> Result<PublicKeyPrepared, BlsError> {
-	PublicKeyPrepared::from_bytes_unchecked(&pubkey.0).map_err(|_| BlsError::InvalidPublicKey)
+	PublicKeyPrepared::from_bytes(&pubkey.0).map_err(|_| BlsError::InvalidPublicKey)
 }
 
 /// Prepare for G1 public keys.
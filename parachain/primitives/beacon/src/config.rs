@@ -8,3 +8,12 @@ pub const LOGS_BLOOM_SIZE: usize = 256;
 
 pub const PUBKEY_SIZE: usize = 48;
 pub const SIGNATURE_SIZE: usize = 96;
+
+/// Sync committee size of the `minimal` consensus preset.
+pub const MINIMAL_SYNC_COMMITTEE_SIZE: usize = 32;
+/// Sync committee size of the `mainnet` consensus preset, also used by Gnosis.
+pub const MAINNET_SYNC_COMMITTEE_SIZE: usize = 512;
+/// Upper bound on the sync committee size of all supported consensus presets.
+pub const MAX_SYNC_COMMITTEE_SIZE: u32 = MAINNET_SYNC_COMMITTEE_SIZE as u32;
+/// Upper bound on the size of the packed sync committee participation bits.
+pub const MAX_SYNC_COMMITTEE_BITS_SIZE: u32 = MAX_SYNC_COMMITTEE_SIZE / 8;
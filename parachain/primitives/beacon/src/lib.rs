@@ -8,6 +8,8 @@ pub mod config;
 pub mod merkle_proof;
 pub mod receipt;
 pub mod ssz;
+pub mod state_proof;
+pub mod transaction;
 pub mod types;
 pub mod updates;
 
@@ -15,17 +17,23 @@ pub mod updates;
 mod serde_utils;
 
 pub use types::{
-	BeaconHeader, CompactBeaconState, CompactExecutionHeader, ExecutionHeaderState,
-	ExecutionPayloadHeader, FinalizedHeaderState, Fork, ForkData, ForkVersion, ForkVersions, Mode,
-	PublicKey, Signature, SigningData, SyncAggregate, SyncCommittee, SyncCommitteePrepared,
+	BeaconHeader, CompactBeaconState, CompactExecutionHeader, EquivocationEvidence,
+	ExecutionHeaderState, ExecutionPayloadHeader, FinalizedHeaderState, Fork, ForkData,
+	ForkVersion, ForkVersions, Mode, PublicKey, Signature, SigningData, SyncAggregate,
+	SyncCommittee, SyncCommitteePrepared, VersionedExecutionPayloadHeader,
+};
+pub use updates::{
+	AncestryProof, CheckpointUpdate, ExecutionBlockRoots, ExecutionHeaderUpdate,
+	NextSyncCommitteeUpdate, Update,
 };
-pub use updates::{CheckpointUpdate, ExecutionHeaderUpdate, NextSyncCommitteeUpdate, Update};
 
 pub use bits::decompress_sync_committee_bits;
 pub use bls::{
-	fast_aggregate_verify, prepare_aggregate_pubkey, prepare_aggregate_pubkey_from_absent,
-	prepare_aggregate_signature, prepare_g1_pubkeys, AggregatePublicKey, AggregateSignature,
-	BlsError, PublicKeyPrepared, SignaturePrepared,
+	fast_aggregate_verify, fast_aggregate_verify_host, prepare_aggregate_pubkey,
+	prepare_aggregate_pubkey_from_absent, prepare_aggregate_signature, prepare_g1_pubkeys,
+	AggregatePublicKey, AggregateSignature, BlsError, PublicKeyPrepared, SignaturePrepared,
 };
 pub use merkle_proof::verify_merkle_branch;
 pub use receipt::verify_receipt_proof;
+pub use state_proof::{verify_account_proof, verify_storage_proof};
+pub use transaction::verify_transaction_proof;
@@ -20,7 +20,8 @@ pub fn verify_merkle_branch(
 	root == compute_merkle_root(leaf, branch, index)
 }
 
-fn compute_merkle_root(leaf: H256, proof: &[H256], index: usize) -> H256 {
+/// Computes the merkle root from `leaf` at the subtree `index` and its `proof`.
+pub fn compute_merkle_root(leaf: H256, proof: &[H256], index: usize) -> H256 {
 	let mut value: [u8; 32] = leaf.into();
 	for (i, node) in proof.iter().enumerate() {
 		let mut data = [0u8; 64];
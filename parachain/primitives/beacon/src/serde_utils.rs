@@ -1,71 +1,10 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+use frame_support::{traits::Get, BoundedVec};
 use sp_core::U256;
 
 use core::fmt::Formatter;
-use serde::{Deserialize, Deserializer};
-
-// helper to deserialize arbitrary arrays like [T; N]
-pub mod arrays {
-	use std::{convert::TryInto, marker::PhantomData};
-
-	use serde::{
-		de::{SeqAccess, Visitor},
-		ser::SerializeTuple,
-		Deserialize, Deserializer, Serialize, Serializer,
-	};
-
-	pub fn serialize<S: Serializer, T: Serialize, const N: usize>(
-		data: &[T; N],
-		ser: S,
-	) -> Result<S::Ok, S::Error> {
-		let mut s = ser.serialize_tuple(N)?;
-		for item in data {
-			s.serialize_element(item)?;
-		}
-		s.end()
-	}
-
-	struct ArrayVisitor<T, const N: usize>(PhantomData<T>);
-
-	impl<'de, T, const N: usize> Visitor<'de> for ArrayVisitor<T, N>
-	where
-		T: Deserialize<'de>,
-	{
-		type Value = [T; N];
-
-		fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-			formatter.write_str(&format!("an array of length {}", N))
-		}
-
-		#[inline]
-		fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-		where
-			A: SeqAccess<'de>,
-		{
-			// can be optimized using MaybeUninit
-			let mut data = Vec::with_capacity(N);
-			for _ in 0..N {
-				match (seq.next_element())? {
-					Some(val) => data.push(val),
-					None => return Err(serde::de::Error::invalid_length(N, &self)),
-				}
-			}
-			match data.try_into() {
-				Ok(arr) => Ok(arr),
-				Err(_) => unreachable!(),
-			}
-		}
-	}
-
-	pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
-	where
-		D: Deserializer<'de>,
-		T: Deserialize<'de>,
-	{
-		deserializer.deserialize_tuple(N, ArrayVisitor::<T, N>(PhantomData))
-	}
-}
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 pub(crate) fn from_hex_to_bytes<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
 where
@@ -86,6 +25,42 @@ where
 	Ok(hex_bytes)
 }
 
+pub(crate) fn from_hex_to_bounded_bytes<'de, D, S>(
+	deserializer: D,
+) -> Result<BoundedVec<u8, S>, D::Error>
+where
+	D: Deserializer<'de>,
+	S: Get<u32>,
+{
+	from_hex_to_bytes(deserializer)?
+		.try_into()
+		.map_err(|_| serde::de::Error::custom("hex data exceeds the maximum length"))
+}
+
+/// (De)serializes a bounded vector as a plain sequence, rejecting sequences over the bound.
+pub(crate) mod bounded_vec {
+	use super::*;
+
+	pub fn serialize<T, S, B>(value: &BoundedVec<T, B>, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		T: Serialize,
+		S: Serializer,
+	{
+		value.as_slice().serialize(serializer)
+	}
+
+	pub fn deserialize<'de, T, D, B>(deserializer: D) -> Result<BoundedVec<T, B>, D::Error>
+	where
+		T: Deserialize<'de>,
+		D: Deserializer<'de>,
+		B: Get<u32>,
+	{
+		Vec::<T>::deserialize(deserializer)?
+			.try_into()
+			.map_err(|_| serde::de::Error::custom("sequence exceeds the maximum length"))
+	}
+}
+
 pub(crate) fn from_int_to_u256<'de, D>(deserializer: D) -> Result<U256, D::Error>
 where
 	D: Deserializer<'de>,
@@ -42,10 +42,14 @@ pub struct SSZSyncCommittee<const COMMITTEE_SIZE: usize> {
 	pub aggregate_pubkey: Vector<u8, PUBKEY_SIZE>,
 }
 
-impl<const COMMITTEE_SIZE: usize> From<SyncCommittee<COMMITTEE_SIZE>>
-	for SSZSyncCommittee<COMMITTEE_SIZE>
-{
-	fn from(sync_committee: SyncCommittee<COMMITTEE_SIZE>) -> Self {
+impl<const COMMITTEE_SIZE: usize> TryFrom<&SyncCommittee> for SSZSyncCommittee<COMMITTEE_SIZE> {
+	type Error = MerkleizationError;
+
+	fn try_from(sync_committee: &SyncCommittee) -> Result<Self, Self::Error> {
+		if sync_committee.pubkeys.len() != COMMITTEE_SIZE {
+			return Err(MerkleizationError::InputExceedsLimit(sync_committee.pubkeys.len()))
+		}
+
 		let mut pubkeys_vec = Vec::new();
 
 		for pubkey in sync_committee.pubkeys.iter() {
@@ -60,7 +64,7 @@ impl<const COMMITTEE_SIZE: usize> From<SyncCommittee<COMMITTEE_SIZE>>
 		let aggregate_pubkey =
 			Vector::<u8, PUBKEY_SIZE>::from_iter(sync_committee.aggregate_pubkey.0);
 
-		SSZSyncCommittee { pubkeys, aggregate_pubkey }
+		Ok(SSZSyncCommittee { pubkeys, aggregate_pubkey })
 	}
 }
 
@@ -70,19 +74,21 @@ pub struct SSZSyncAggregate<const COMMITTEE_SIZE: usize> {
 	pub sync_committee_signature: Vector<u8, SIGNATURE_SIZE>,
 }
 
-impl<const COMMITTEE_SIZE: usize, const COMMITTEE_BITS_SIZE: usize>
-	From<SyncAggregate<COMMITTEE_SIZE, COMMITTEE_BITS_SIZE>> for SSZSyncAggregate<COMMITTEE_SIZE>
-{
-	fn from(sync_aggregate: SyncAggregate<COMMITTEE_SIZE, COMMITTEE_BITS_SIZE>) -> Self {
-		SSZSyncAggregate {
-			sync_committee_bits: Bitvector::<COMMITTEE_SIZE>::deserialize(
-				&sync_aggregate.sync_committee_bits,
-			)
-			.expect("checked statically; qed"),
+impl<const COMMITTEE_SIZE: usize> TryFrom<&SyncAggregate> for SSZSyncAggregate<COMMITTEE_SIZE> {
+	type Error = MerkleizationError;
+
+	fn try_from(sync_aggregate: &SyncAggregate) -> Result<Self, Self::Error> {
+		let sync_committee_bits =
+			Bitvector::<COMMITTEE_SIZE>::deserialize(&sync_aggregate.sync_committee_bits).map_err(
+				|_| MerkleizationError::InputExceedsLimit(sync_aggregate.sync_committee_bits.len()),
+			)?;
+
+		Ok(SSZSyncAggregate {
+			sync_committee_bits,
 			sync_committee_signature: Vector::<u8, SIGNATURE_SIZE>::from_iter(
 				sync_aggregate.sync_committee_signature.0,
 			),
-		}
+		})
 	}
 }
 
@@ -165,6 +171,73 @@ impl From<ExecutionPayloadHeader> for SSZExecutionPayloadHeader {
 	}
 }
 
+pub mod deneb {
+	use crate::{
+		config::{EXTRA_DATA_SIZE, FEE_RECIPIENT_SIZE, LOGS_BLOOM_SIZE},
+		types::deneb::ExecutionPayloadHeader,
+	};
+	use byte_slice_cast::AsByteSlice;
+	use sp_std::{vec, vec::Vec};
+	use ssz_rs::{
+		prelude::{List, Vector},
+		Deserialize, SimpleSerialize, Sized, U256,
+	};
+	use ssz_rs_derive::SimpleSerialize as SimpleSerializeDerive;
+
+	#[derive(Default, SimpleSerializeDerive, Clone, Debug)]
+	pub struct SSZExecutionPayloadHeader {
+		pub parent_hash: [u8; 32],
+		pub fee_recipient: Vector<u8, FEE_RECIPIENT_SIZE>,
+		pub state_root: [u8; 32],
+		pub receipts_root: [u8; 32],
+		pub logs_bloom: Vector<u8, LOGS_BLOOM_SIZE>,
+		pub prev_randao: [u8; 32],
+		pub block_number: u64,
+		pub gas_limit: u64,
+		pub gas_used: u64,
+		pub timestamp: u64,
+		pub extra_data: List<u8, EXTRA_DATA_SIZE>,
+		pub base_fee_per_gas: U256,
+		pub block_hash: [u8; 32],
+		pub transactions_root: [u8; 32],
+		pub withdrawals_root: [u8; 32],
+		pub blob_gas_used: u64,
+		pub excess_blob_gas: u64,
+	}
+
+	impl From<ExecutionPayloadHeader> for SSZExecutionPayloadHeader {
+		fn from(payload: ExecutionPayloadHeader) -> Self {
+			SSZExecutionPayloadHeader {
+				parent_hash: payload.parent_hash.to_fixed_bytes(),
+				fee_recipient: Vector::<u8, FEE_RECIPIENT_SIZE>::from_iter(
+					payload.fee_recipient.to_fixed_bytes(),
+				),
+				state_root: payload.state_root.to_fixed_bytes(),
+				receipts_root: payload.receipts_root.to_fixed_bytes(),
+				logs_bloom: Vector::<u8, LOGS_BLOOM_SIZE>::from_iter(payload.logs_bloom),
+				prev_randao: payload.prev_randao.to_fixed_bytes(),
+				block_number: payload.block_number,
+				gas_limit: payload.gas_limit,
+				gas_used: payload.gas_used,
+				timestamp: payload.timestamp,
+				extra_data: List::<u8, EXTRA_DATA_SIZE>::from_iter(payload.extra_data),
+				base_fee_per_gas: U256::from_bytes_le(
+					payload
+						.base_fee_per_gas
+						.as_byte_slice()
+						.try_into()
+						.expect("checked in prep; qed"),
+				),
+				block_hash: payload.block_hash.to_fixed_bytes(),
+				transactions_root: payload.transactions_root.to_fixed_bytes(),
+				withdrawals_root: payload.withdrawals_root.to_fixed_bytes(),
+				blob_gas_used: payload.blob_gas_used,
+				excess_blob_gas: payload.excess_blob_gas,
+			}
+		}
+	}
+}
+
 pub fn hash_tree_root<T: SimpleSerialize>(mut object: T) -> Result<H256, MerkleizationError> {
 	match object.hash_tree_root() {
 		Ok(node) => {
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_std::prelude::*;
+
+use snowbridge_ethereum::{mpt, Account};
+
+/// Verifies an `eth_getProof` account proof against an execution state root. Returns `None` if
+/// the proof shows that the account does not exist.
+pub fn verify_account_proof(
+	state_root: H256,
+	address: H160,
+	proof: &[Vec<u8>],
+) -> Result<Option<Account>, mpt::ProofError> {
+	let key = keccak_256(address.as_bytes());
+	match mpt::verify_proof(state_root, &key, proof)? {
+		Some(value) => Ok(Some(rlp::decode(&value)?)),
+		None => Ok(None),
+	}
+}
+
+/// Verifies an `eth_getProof` storage proof against the storage root of an account. Unset storage
+/// slots are zero.
+pub fn verify_storage_proof(
+	storage_root: H256,
+	key: H256,
+	proof: &[Vec<u8>],
+) -> Result<U256, mpt::ProofError> {
+	let key = keccak_256(key.as_bytes());
+	match mpt::verify_proof(storage_root, &key, proof)? {
+		Some(value) => Ok(rlp::decode(&value)?),
+		None => Ok(U256::zero()),
+	}
+}
@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+use sp_core::H256;
+use sp_std::prelude::*;
+
+use snowbridge_ethereum::{mpt, Transaction};
+
+/// Verifies that `proof` proves the transaction at `tx_index` against `transactions_root`.
+/// Transactions are keyed by their RLP encoded index in the transactions trie, like receipts.
+/// Returns `None` if the proof is invalid.
+pub fn verify_transaction_proof(
+	transactions_root: H256,
+	tx_index: u32,
+	proof: &[Vec<u8>],
+) -> Option<Result<Transaction, rlp::DecoderError>> {
+	match mpt::verify_proof(transactions_root, &rlp::encode(&tx_index), proof) {
+		Ok(Some(data)) => Some(rlp::decode(&data)),
+		_ => None,
+	}
+}
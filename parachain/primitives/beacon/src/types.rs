@@ -1,13 +1,18 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound};
+use frame_support::{
+	traits::ConstU32, BoundedVec, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
 use scale_info::TypeInfo;
 use sp_core::{H160, H256, U256};
 use sp_runtime::RuntimeDebug;
-use sp_std::{boxed::Box, prelude::*};
+use sp_std::prelude::*;
 
-use crate::config::{PUBKEY_SIZE, SIGNATURE_SIZE};
+use crate::config::{
+	MAINNET_SYNC_COMMITTEE_SIZE, MAX_SYNC_COMMITTEE_BITS_SIZE, MAX_SYNC_COMMITTEE_SIZE,
+	MINIMAL_SYNC_COMMITTEE_SIZE, PUBKEY_SIZE, SIGNATURE_SIZE,
+};
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -23,27 +28,42 @@ use ssz_rs::MerkleizationError;
 
 pub use crate::bits::decompress_sync_committee_bits;
 
-use crate::bls::{prepare_g1_pubkeys, prepare_milagro_pubkey, BlsError};
+use crate::{
+	bls::{prepare_milagro_pubkey, BlsError},
+	updates::ExecutionBlockRoots,
+};
 use milagro_bls::PublicKey as PublicKeyPrepared;
+use snowbridge_ethereum::ExecutionHeader;
 
 pub type ValidatorIndex = u64;
 pub type ForkVersion = [u8; 4];
 
-#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct ForkVersions {
 	pub genesis: Fork,
 	pub altair: Fork,
 	pub bellatrix: Fork,
 	pub capella: Fork,
+	pub deneb: Fork,
+	pub electra: Fork,
 }
 
-#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+impl ForkVersions {
+	/// Returns the forks in order of activation.
+	pub fn forks(&self) -> [&Fork; 6] {
+		[&self.genesis, &self.altair, &self.bellatrix, &self.capella, &self.deneb, &self.electra]
+	}
+}
+
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Fork {
 	pub version: [u8; 4],
 	pub epoch: u64,
 }
 
-#[derive(Copy, Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[derive(Copy, Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct PublicKey(pub [u8; PUBKEY_SIZE]);
 
 impl Default for PublicKey {
@@ -148,66 +168,55 @@ impl SigningData {
 	}
 }
 
-/// Sync committee as it is stored in the runtime storage.
-#[derive(
-	Encode, Decode, PartialEqNoBound, CloneNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen,
-)]
-#[cfg_attr(
-	feature = "std",
-	derive(Serialize, Deserialize),
-	serde(deny_unknown_fields, bound(serialize = ""), bound(deserialize = ""))
-)]
-#[codec(mel_bound())]
-pub struct SyncCommittee<const COMMITTEE_SIZE: usize> {
-	#[cfg_attr(feature = "std", serde(with = "crate::serde_utils::arrays"))]
-	pub pubkeys: [PublicKey; COMMITTEE_SIZE],
+/// Sync committee as it is received from the relayer. The number of members depends on the
+/// consensus preset of the network.
+#[derive(Encode, Decode, Clone, Default, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(deny_unknown_fields))]
+pub struct SyncCommittee {
+	#[cfg_attr(feature = "std", serde(with = "crate::serde_utils::bounded_vec"))]
+	pub pubkeys: BoundedVec<PublicKey, ConstU32<MAX_SYNC_COMMITTEE_SIZE>>,
 	pub aggregate_pubkey: PublicKey,
 }
 
-impl<const COMMITTEE_SIZE: usize> Default for SyncCommittee<COMMITTEE_SIZE> {
-	fn default() -> Self {
-		SyncCommittee {
-			pubkeys: [Default::default(); COMMITTEE_SIZE],
-			aggregate_pubkey: Default::default(),
-		}
-	}
-}
-
-impl<const COMMITTEE_SIZE: usize> SyncCommittee<COMMITTEE_SIZE> {
+impl SyncCommittee {
+	/// Computes the hash tree root of the sync committee. Since SSZ vectors are fixed size, the
+	/// committee must have the size of one of the supported consensus presets.
 	pub fn hash_tree_root(&self) -> Result<H256, MerkleizationError> {
-		hash_tree_root::<SSZSyncCommittee<COMMITTEE_SIZE>>(self.clone().into())
+		match self.pubkeys.len() {
+			MINIMAL_SYNC_COMMITTEE_SIZE =>
+				hash_tree_root::<SSZSyncCommittee<MINIMAL_SYNC_COMMITTEE_SIZE>>(self.try_into()?),
+			MAINNET_SYNC_COMMITTEE_SIZE =>
+				hash_tree_root::<SSZSyncCommittee<MAINNET_SYNC_COMMITTEE_SIZE>>(self.try_into()?),
+			size => Err(MerkleizationError::InputExceedsLimit(size)),
+		}
 	}
 }
 
-/// Prepared G1 public key of sync committee as it is stored in the runtime storage.
-#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
-pub struct SyncCommitteePrepared<const COMMITTEE_SIZE: usize> {
+/// Sync committee as it is stored in the runtime storage. Public keys are kept compressed and
+/// only the absent participants' keys are decompressed when verifying a sync aggregate, while
+/// the aggregate public key is kept as a prepared G1 point.
+#[derive(Clone, Default, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct SyncCommitteePrepared {
 	pub root: H256,
-	pub pubkeys: Box<[PublicKeyPrepared; COMMITTEE_SIZE]>,
+	pub pubkeys: BoundedVec<PublicKey, ConstU32<MAX_SYNC_COMMITTEE_SIZE>>,
 	pub aggregate_pubkey: PublicKeyPrepared,
 }
 
-impl<const COMMITTEE_SIZE: usize> Default for SyncCommitteePrepared<COMMITTEE_SIZE> {
-	fn default() -> Self {
-		SyncCommitteePrepared {
-			root: H256::default(),
-			pubkeys: Box::new([PublicKeyPrepared::default(); COMMITTEE_SIZE]),
-			aggregate_pubkey: PublicKeyPrepared::default(),
-		}
-	}
-}
-
-impl<const COMMITTEE_SIZE: usize> TryFrom<&SyncCommittee<COMMITTEE_SIZE>>
-	for SyncCommitteePrepared<COMMITTEE_SIZE>
-{
+impl TryFrom<&SyncCommittee> for SyncCommitteePrepared {
 	type Error = BlsError;
 
-	fn try_from(sync_committee: &SyncCommittee<COMMITTEE_SIZE>) -> Result<Self, Self::Error> {
-		let g1_pubkeys = prepare_g1_pubkeys(&sync_committee.pubkeys)?;
-		let sync_committee_root = sync_committee.hash_tree_root().expect("checked statically; qed");
+	fn try_from(sync_committee: &SyncCommittee) -> Result<Self, Self::Error> {
+		let sync_committee_root =
+			sync_committee.hash_tree_root().map_err(|_| BlsError::InvalidPublicKey)?;
+
+		// The member keys are stored compressed, so they are validated once here, at import,
+		// rather than when the keys of absent participants are decompressed.
+		for pubkey in sync_committee.pubkeys.iter() {
+			prepare_milagro_pubkey(pubkey)?;
+		}
 
-		Ok(SyncCommitteePrepared::<COMMITTEE_SIZE> {
-			pubkeys: g1_pubkeys.try_into().expect("checked statically; qed"),
+		Ok(SyncCommitteePrepared {
+			pubkeys: sync_committee.pubkeys.clone(),
 			aggregate_pubkey: prepare_milagro_pubkey(&sync_committee.aggregate_pubkey)?,
 			root: sync_committee_root,
 		})
@@ -240,65 +249,29 @@ impl BeaconHeader {
 	}
 }
 
-#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo)]
-#[cfg_attr(
-	feature = "std",
-	derive(Deserialize),
-	serde(
-		try_from = "IntermediateSyncAggregate",
-		deny_unknown_fields,
-		bound(serialize = ""),
-		bound(deserialize = "")
-	)
-)]
-#[codec(mel_bound())]
-pub struct SyncAggregate<const COMMITTEE_SIZE: usize, const COMMITTEE_BITS_SIZE: usize> {
-	pub sync_committee_bits: [u8; COMMITTEE_BITS_SIZE],
+#[derive(Default, Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize), serde(deny_unknown_fields))]
+pub struct SyncAggregate {
+	/// Participation bits of the sync committee members, packed as an SSZ bitvector.
+	#[cfg_attr(
+		feature = "std",
+		serde(deserialize_with = "crate::serde_utils::from_hex_to_bounded_bytes")
+	)]
+	pub sync_committee_bits: BoundedVec<u8, ConstU32<MAX_SYNC_COMMITTEE_BITS_SIZE>>,
 	pub sync_committee_signature: Signature,
 }
 
-impl<const COMMITTEE_SIZE: usize, const COMMITTEE_BITS_SIZE: usize> Default
-	for SyncAggregate<COMMITTEE_SIZE, COMMITTEE_BITS_SIZE>
-{
-	fn default() -> Self {
-		SyncAggregate {
-			sync_committee_bits: [0; COMMITTEE_BITS_SIZE],
-			sync_committee_signature: Default::default(),
-		}
-	}
-}
-
-impl<const COMMITTEE_SIZE: usize, const COMMITTEE_BITS_SIZE: usize>
-	SyncAggregate<COMMITTEE_SIZE, COMMITTEE_BITS_SIZE>
-{
+impl SyncAggregate {
+	/// Computes the hash tree root of the sync aggregate. Since SSZ bitvectors are fixed size,
+	/// the bits must cover the sync committee of one of the supported consensus presets.
 	pub fn hash_tree_root(&self) -> Result<H256, MerkleizationError> {
-		hash_tree_root::<SSZSyncAggregate<COMMITTEE_SIZE>>(self.clone().into())
-	}
-}
-
-/// Serde deserialization helper for SyncAggregate
-#[cfg(feature = "std")]
-#[derive(Deserialize)]
-struct IntermediateSyncAggregate {
-	#[cfg_attr(feature = "std", serde(deserialize_with = "crate::serde_utils::from_hex_to_bytes"))]
-	pub sync_committee_bits: Vec<u8>,
-	pub sync_committee_signature: Signature,
-}
-
-#[cfg(feature = "std")]
-impl<const COMMITTEE_SIZE: usize, const COMMITTEE_BITS_SIZE: usize>
-	TryFrom<IntermediateSyncAggregate> for SyncAggregate<COMMITTEE_SIZE, COMMITTEE_BITS_SIZE>
-{
-	type Error = String;
-
-	fn try_from(other: IntermediateSyncAggregate) -> Result<Self, Self::Error> {
-		Ok(Self {
-			sync_committee_bits: other
-				.sync_committee_bits
-				.try_into()
-				.map_err(|_| "unexpected length".to_owned())?,
-			sync_committee_signature: other.sync_committee_signature,
-		})
+		match self.sync_committee_bits.len() * 8 {
+			MINIMAL_SYNC_COMMITTEE_SIZE =>
+				hash_tree_root::<SSZSyncAggregate<MINIMAL_SYNC_COMMITTEE_SIZE>>(self.try_into()?),
+			MAINNET_SYNC_COMMITTEE_SIZE =>
+				hash_tree_root::<SSZSyncAggregate<MAINNET_SYNC_COMMITTEE_SIZE>>(self.try_into()?),
+			size => Err(MerkleizationError::InputExceedsLimit(size)),
+		}
 	}
 }
 
@@ -356,6 +329,10 @@ pub struct CompactExecutionHeader {
 	pub block_number: u64,
 	pub state_root: H256,
 	pub receipts_root: H256,
+	/// The root of the transactions trie. Execution payload headers only commit to the SSZ root
+	/// of the transactions, so this is only known once the execution block header has been
+	/// verified.
+	pub transactions_root: Option<H256>,
 }
 
 impl From<ExecutionPayloadHeader> for CompactExecutionHeader {
@@ -365,6 +342,132 @@ impl From<ExecutionPayloadHeader> for CompactExecutionHeader {
 			block_number: execution_payload.block_number,
 			state_root: execution_payload.state_root,
 			receipts_root: execution_payload.receipts_root,
+			transactions_root: None,
+		}
+	}
+}
+
+/// An execution payload header, versioned by the fork in which it was produced. The SSZ
+/// container differs between forks, so the hash tree root depends on the version.
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo)]
+#[cfg_attr(
+	feature = "std",
+	derive(Deserialize),
+	serde(deny_unknown_fields, bound(serialize = ""), bound(deserialize = ""))
+)]
+#[codec(mel_bound())]
+pub enum VersionedExecutionPayloadHeader {
+	Capella(ExecutionPayloadHeader),
+	Deneb(deneb::ExecutionPayloadHeader),
+}
+
+impl VersionedExecutionPayloadHeader {
+	pub fn hash_tree_root(&self) -> Result<H256, MerkleizationError> {
+		match self {
+			VersionedExecutionPayloadHeader::Capella(execution_payload_header) =>
+				execution_payload_header.hash_tree_root(),
+			VersionedExecutionPayloadHeader::Deneb(execution_payload_header) =>
+				execution_payload_header.hash_tree_root(),
+		}
+	}
+
+	pub fn block_hash(&self) -> H256 {
+		match self {
+			VersionedExecutionPayloadHeader::Capella(execution_payload_header) =>
+				execution_payload_header.block_hash,
+			VersionedExecutionPayloadHeader::Deneb(execution_payload_header) =>
+				execution_payload_header.block_hash,
+		}
+	}
+
+	pub fn block_number(&self) -> u64 {
+		match self {
+			VersionedExecutionPayloadHeader::Capella(execution_payload_header) =>
+				execution_payload_header.block_number,
+			VersionedExecutionPayloadHeader::Deneb(execution_payload_header) =>
+				execution_payload_header.block_number,
+		}
+	}
+
+	pub fn receipts_root(&self) -> H256 {
+		match self {
+			VersionedExecutionPayloadHeader::Capella(execution_payload_header) =>
+				execution_payload_header.receipts_root,
+			VersionedExecutionPayloadHeader::Deneb(execution_payload_header) =>
+				execution_payload_header.receipts_root,
+		}
+	}
+
+	pub fn parent_hash(&self) -> H256 {
+		match self {
+			VersionedExecutionPayloadHeader::Capella(execution_payload_header) =>
+				execution_payload_header.parent_hash,
+			VersionedExecutionPayloadHeader::Deneb(execution_payload_header) =>
+				execution_payload_header.parent_hash,
+		}
+	}
+
+	/// Returns the execution block header, from which the execution block hash is computed.
+	/// `parent_beacon_block_root` is the parent root of the beacon block containing the payload.
+	pub fn execution_header(
+		&self,
+		roots: &ExecutionBlockRoots,
+		parent_beacon_block_root: H256,
+	) -> ExecutionHeader {
+		match self {
+			VersionedExecutionPayloadHeader::Capella(h) => ExecutionHeader {
+				parent_hash: h.parent_hash,
+				fee_recipient: h.fee_recipient,
+				state_root: h.state_root,
+				transactions_root: roots.transactions_root,
+				receipts_root: h.receipts_root,
+				logs_bloom: h.logs_bloom.clone(),
+				prev_randao: h.prev_randao,
+				block_number: h.block_number,
+				gas_limit: h.gas_limit,
+				gas_used: h.gas_used,
+				timestamp: h.timestamp,
+				extra_data: h.extra_data.clone(),
+				base_fee_per_gas: h.base_fee_per_gas,
+				withdrawals_root: Some(roots.withdrawals_root),
+				blob_gas_used: None,
+				excess_blob_gas: None,
+				parent_beacon_block_root: None,
+				requests_hash: None,
+			},
+			VersionedExecutionPayloadHeader::Deneb(h) => ExecutionHeader {
+				parent_hash: h.parent_hash,
+				fee_recipient: h.fee_recipient,
+				state_root: h.state_root,
+				transactions_root: roots.transactions_root,
+				receipts_root: h.receipts_root,
+				logs_bloom: h.logs_bloom.clone(),
+				prev_randao: h.prev_randao,
+				block_number: h.block_number,
+				gas_limit: h.gas_limit,
+				gas_used: h.gas_used,
+				timestamp: h.timestamp,
+				extra_data: h.extra_data.clone(),
+				base_fee_per_gas: h.base_fee_per_gas,
+				withdrawals_root: Some(roots.withdrawals_root),
+				blob_gas_used: Some(h.blob_gas_used),
+				excess_blob_gas: Some(h.excess_blob_gas),
+				parent_beacon_block_root: Some(parent_beacon_block_root),
+				// Electra reuses the Deneb payload header, but its execution block header also
+				// commits to the EIP-7685 requests.
+				requests_hash: roots.requests_hash,
+			},
+		}
+	}
+}
+
+impl From<VersionedExecutionPayloadHeader> for CompactExecutionHeader {
+	fn from(versioned_execution_header: VersionedExecutionPayloadHeader) -> Self {
+		match versioned_execution_header {
+			VersionedExecutionPayloadHeader::Capella(execution_payload_header) =>
+				execution_payload_header.into(),
+			VersionedExecutionPayloadHeader::Deneb(execution_payload_header) =>
+				execution_payload_header.into(),
 		}
 	}
 }
@@ -386,6 +489,30 @@ pub struct CompactBeaconState {
 	pub block_roots_root: H256,
 }
 
+/// Evidence of two validly signed updates finalizing different beacon headers at the same slot.
+#[derive(
+	Encode,
+	Decode,
+	Copy,
+	CloneNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct EquivocationEvidence {
+	/// Slot of the conflicting finalized headers.
+	pub slot: u64,
+	/// Root of the finalized header already imported at `slot`.
+	pub finalized_block_root: H256,
+	/// Root of the conflicting finalized header.
+	pub conflicting_block_root: H256,
+	/// Attested header of the update which finalized the conflicting header.
+	pub attested_header: BeaconHeader,
+	/// Slot at which the sync committee signed `attested_header`.
+	pub signature_slot: u64,
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -467,10 +594,21 @@ mod tests {
 		);
 	}
 
+	#[test]
+	pub fn test_decode_rejects_oversized_sync_committee() {
+		let pubkeys = vec![PublicKey([0u8; PUBKEY_SIZE]); MAX_SYNC_COMMITTEE_SIZE as usize + 1];
+		let encoded = (pubkeys, PublicKey([0u8; PUBKEY_SIZE])).encode();
+		assert!(SyncCommittee::decode(&mut &encoded[..]).is_err());
+
+		let bits = vec![0xffu8; MAX_SYNC_COMMITTEE_BITS_SIZE as usize + 1];
+		let encoded = (bits, Signature([0u8; SIGNATURE_SIZE])).encode();
+		assert!(SyncAggregate::decode(&mut &encoded[..]).is_err());
+	}
+
 	#[test]
 	pub fn test_hash_sync_aggregate() {
-		let hash_root = SyncAggregate::<512, 64>{
-				sync_committee_bits: hex!("cefffffefffffff767fffbedffffeffffeeffdffffdebffffff7f7dbdf7fffdffffbffcfffdff79dfffbbfefff2ffffff7ddeff7ffffc98ff7fbfffffffffff7").into(),
+		let hash_root = SyncAggregate{
+				sync_committee_bits: hex!("cefffffefffffff767fffbedffffeffffeeffdffffdebffffff7f7dbdf7fffdffffbffcfffdff79dfffbbfefff2ffffff7ddeff7ffffc98ff7fbfffffffffff7").to_vec().try_into().unwrap(),
 				sync_committee_signature: hex!("8af1a8577bba419fe054ee49b16ed28e081dda6d3ba41651634685e890992a0b675e20f8d9f2ec137fe9eb50e838aa6117f9f5410e2e1024c4b4f0e098e55144843ce90b7acde52fe7b94f2a1037342c951dc59f501c92acf7ed944cb6d2b5f7").into(),
 		}.hash_tree_root();
 
@@ -503,11 +641,161 @@ mod tests {
 			}.hash_tree_root();
 		assert!(hash_root.is_ok());
 	}
+
+	#[test]
+	pub fn test_hash_execution_payload_deneb() {
+		let execution_payload_header = deneb::ExecutionPayloadHeader {
+			parent_hash: hex!("8092290aa21b7751576440f77edd02a94058429ce50e63a92d620951fb25eda2")
+				.into(),
+			fee_recipient: hex!("0000000000000000000000000000000000000000").into(),
+			state_root: hex!("96a83e9ddf745346fafcb0b03d57314623df669ed543c110662b21302a0fae8b")
+				.into(),
+			receipts_root: hex!("62d13e9a073dd4c6ab8cfb9b4d5d8eab8a0ce60a1bd0ed32e6c1d17c3b4cd6d7")
+				.into(),
+			logs_bloom: vec![0u8; 256],
+			prev_randao: hex!("62e309d4f5119d1f5c783abc20fc1a549efbab546d8d0b25ff1cfd58be524e67")
+				.into(),
+			block_number: 393,
+			gas_limit: 54492273,
+			gas_used: 199644,
+			timestamp: 1710552813,
+			extra_data: hex!("d983010d0b846765746888676f312e32312e368664617277696e").to_vec(),
+			base_fee_per_gas: U256::from(7),
+			block_hash: hex!("6a9810efb9581d30c1a5c9074f27c68ea779a8c1ae31c213241df16225f4e131")
+				.into(),
+			transactions_root: hex!(
+				"2cfa6ed7327e8807c7973516c5c32a68ef2459e586e8067e113d081c3bd8c07d"
+			)
+			.into(),
+			withdrawals_root: hex!(
+				"792930bbd5baac43bcc798ee49aa8185ef76bb3b44ba62b91d86ae569e4bb535"
+			)
+			.into(),
+			blob_gas_used: 131072,
+			excess_blob_gas: 786432,
+		};
+
+		let hash_root = execution_payload_header.hash_tree_root();
+		assert!(hash_root.is_ok());
+		assert_eq!(
+			hash_root.unwrap(),
+			hex!("28b4d4c5772685a8bbeaacc4cf84a092de18d220bd8baa9f60d76bc53aaaabe8").into()
+		);
+
+		// The versioned header must hash according to its fork, so the same fields hashed as a
+		// Capella header give a different root.
+		let versioned_hash_root =
+			VersionedExecutionPayloadHeader::Deneb(execution_payload_header.clone())
+				.hash_tree_root();
+		assert_eq!(
+			versioned_hash_root.unwrap(),
+			hex!("28b4d4c5772685a8bbeaacc4cf84a092de18d220bd8baa9f60d76bc53aaaabe8").into()
+		);
+		let capella_hash_root = VersionedExecutionPayloadHeader::Capella(ExecutionPayloadHeader {
+			parent_hash: execution_payload_header.parent_hash,
+			fee_recipient: execution_payload_header.fee_recipient,
+			state_root: execution_payload_header.state_root,
+			receipts_root: execution_payload_header.receipts_root,
+			logs_bloom: execution_payload_header.logs_bloom,
+			prev_randao: execution_payload_header.prev_randao,
+			block_number: execution_payload_header.block_number,
+			gas_limit: execution_payload_header.gas_limit,
+			gas_used: execution_payload_header.gas_used,
+			timestamp: execution_payload_header.timestamp,
+			extra_data: execution_payload_header.extra_data,
+			base_fee_per_gas: execution_payload_header.base_fee_per_gas,
+			block_hash: execution_payload_header.block_hash,
+			transactions_root: execution_payload_header.transactions_root,
+			withdrawals_root: execution_payload_header.withdrawals_root,
+		})
+		.hash_tree_root();
+		assert_eq!(
+			capella_hash_root.unwrap(),
+			hex!("254751ee30f9b0d42436309f80afbca4f3ab0fd4ec5e0f489870ab11994f8bbf").into()
+		);
+	}
 }
 
 /// Operating modes for beacon client
-#[derive(Encode, Decode, Copy, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+#[derive(
+	Encode, Decode, Copy, Clone, Default, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
 pub enum Mode {
+	#[default]
 	Active,
 	Blocked,
 }
+
+pub mod deneb {
+	use codec::{Decode, Encode};
+	use frame_support::{CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound};
+	use scale_info::TypeInfo;
+	#[cfg(feature = "std")]
+	use serde::Deserialize;
+	use sp_core::{H160, H256, U256};
+	use sp_std::prelude::*;
+
+	use crate::ssz::{deneb::SSZExecutionPayloadHeader, hash_tree_root};
+	use ssz_rs::MerkleizationError;
+
+	/// ExecutionPayloadHeader
+	/// https://github.com/ethereum/consensus-specs/blob/dev/specs/deneb/beacon-chain.md#executionpayloadheader
+	#[derive(
+		Default, Encode, Decode, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+	)]
+	#[cfg_attr(
+		feature = "std",
+		derive(Deserialize),
+		serde(deny_unknown_fields, bound(serialize = ""), bound(deserialize = ""))
+	)]
+	#[codec(mel_bound())]
+	pub struct ExecutionPayloadHeader {
+		pub parent_hash: H256,
+		pub fee_recipient: H160,
+		pub state_root: H256,
+		pub receipts_root: H256,
+		#[cfg_attr(
+			feature = "std",
+			serde(deserialize_with = "crate::serde_utils::from_hex_to_bytes")
+		)]
+		pub logs_bloom: Vec<u8>,
+		pub prev_randao: H256,
+		pub block_number: u64,
+		pub gas_limit: u64,
+		pub gas_used: u64,
+		pub timestamp: u64,
+		#[cfg_attr(
+			feature = "std",
+			serde(deserialize_with = "crate::serde_utils::from_hex_to_bytes")
+		)]
+		pub extra_data: Vec<u8>,
+		#[cfg_attr(
+			feature = "std",
+			serde(deserialize_with = "crate::serde_utils::from_int_to_u256")
+		)]
+		pub base_fee_per_gas: U256,
+		pub block_hash: H256,
+		pub transactions_root: H256,
+		pub withdrawals_root: H256,
+		pub blob_gas_used: u64,   // [New in Deneb:EIP4844]
+		pub excess_blob_gas: u64, // [New in Deneb:EIP4844]
+	}
+
+	impl ExecutionPayloadHeader {
+		pub fn hash_tree_root(&self) -> Result<H256, MerkleizationError> {
+			hash_tree_root::<SSZExecutionPayloadHeader>(self.clone().into())
+		}
+	}
+
+	impl From<ExecutionPayloadHeader> for crate::CompactExecutionHeader {
+		fn from(execution_payload: ExecutionPayloadHeader) -> Self {
+			Self {
+				parent_hash: execution_payload.parent_hash,
+				block_number: execution_payload.block_number,
+				state_root: execution_payload.state_root,
+				receipts_root: execution_payload.receipts_root,
+				transactions_root: None,
+			}
+		}
+	}
+}
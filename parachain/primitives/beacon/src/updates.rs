@@ -6,34 +6,26 @@ use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_std::prelude::*;
 
-use crate::types::{BeaconHeader, ExecutionPayloadHeader, SyncAggregate, SyncCommittee};
+use crate::types::{BeaconHeader, SyncAggregate, SyncCommittee, VersionedExecutionPayloadHeader};
 
-#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo)]
+#[derive(
+	Default, Encode, Decode, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
 #[cfg_attr(
 	feature = "std",
 	derive(serde::Serialize, serde::Deserialize),
 	serde(deny_unknown_fields, bound(serialize = ""), bound(deserialize = ""))
 )]
-pub struct CheckpointUpdate<const COMMITTEE_SIZE: usize> {
+pub struct CheckpointUpdate {
 	pub header: BeaconHeader,
-	pub current_sync_committee: SyncCommittee<COMMITTEE_SIZE>,
+	pub current_sync_committee: SyncCommittee,
 	pub current_sync_committee_branch: Vec<H256>,
 	pub validators_root: H256,
 	pub block_roots_root: H256,
 	pub block_roots_branch: Vec<H256>,
-}
-
-impl<const COMMITTEE_SIZE: usize> Default for CheckpointUpdate<COMMITTEE_SIZE> {
-	fn default() -> Self {
-		CheckpointUpdate {
-			header: Default::default(),
-			current_sync_committee: Default::default(),
-			current_sync_committee_branch: Default::default(),
-			validators_root: Default::default(),
-			block_roots_root: Default::default(),
-			block_roots_branch: Default::default(),
-		}
-	}
+	/// The next sync committee, if known, which saves waiting for a sync committee update.
+	#[cfg_attr(feature = "std", serde(default))]
+	pub next_sync_committee_update: Option<NextSyncCommitteeUpdate>,
 }
 
 #[derive(
@@ -44,38 +36,47 @@ impl<const COMMITTEE_SIZE: usize> Default for CheckpointUpdate<COMMITTEE_SIZE> {
 	derive(serde::Deserialize),
 	serde(deny_unknown_fields, bound(serialize = ""), bound(deserialize = ""))
 )]
-pub struct Update<const COMMITTEE_SIZE: usize, const COMMITTEE_BITS_SIZE: usize> {
+pub struct Update {
 	/// A recent header attesting to the finalized header, using its `state_root`.
 	pub attested_header: BeaconHeader,
 	/// The signing data that the sync committee produced for this attested header, including
 	/// who participated in the vote and the resulting signature.
-	pub sync_aggregate: SyncAggregate<COMMITTEE_SIZE, COMMITTEE_BITS_SIZE>,
+	pub sync_aggregate: SyncAggregate,
 	/// The slot at which the sync aggregate can be found, typically attested_header.slot + 1, if
 	/// the next slot block was not missed.
 	pub signature_slot: u64,
 	/// The next sync committee for the next sync committee period, if present.
-	pub next_sync_committee_update: Option<NextSyncCommitteeUpdate<COMMITTEE_SIZE>>,
-	/// The latest finalized header.
+	pub next_sync_committee_update: Option<NextSyncCommitteeUpdate>,
+	/// The latest finalized header, or empty if the update does not prove finality.
 	pub finalized_header: BeaconHeader,
 	/// The merkle proof testifying to the finalized header, using the `attested_header.state_root`
 	/// as tree root.
 	pub finality_branch: Vec<H256>,
 	/// The finalized_header's `block_roots` root in the beacon state, used for ancestry proofs.
+	/// For updates without finality, optionally that of the `attested_header` instead.
 	pub block_roots_root: H256,
 	/// The merkle path to prove the `block_roots_root` value.
 	pub block_roots_branch: Vec<H256>,
 }
 
+impl Update {
+	/// Whether the update proves a finalized header. Updates without finality leave
+	/// `finalized_header` empty.
+	pub fn is_finality_update(&self) -> bool {
+		self.finalized_header != BeaconHeader::default()
+	}
+}
+
 #[derive(
 	Default, Encode, Decode, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
 )]
 #[cfg_attr(
 	feature = "std",
-	derive(serde::Deserialize),
+	derive(serde::Serialize, serde::Deserialize),
 	serde(deny_unknown_fields, bound(serialize = ""), bound(deserialize = ""))
 )]
-pub struct NextSyncCommitteeUpdate<const COMMITTEE_SIZE: usize> {
-	pub next_sync_committee: SyncCommittee<COMMITTEE_SIZE>,
+pub struct NextSyncCommitteeUpdate {
+	pub next_sync_committee: SyncCommittee,
 	pub next_sync_committee_branch: Vec<H256>,
 }
 
@@ -91,20 +92,61 @@ pub struct ExecutionHeaderUpdate {
 	/// Proof that `header` is an ancestor of a finalized header
 	pub ancestry_proof: Option<AncestryProof>,
 	/// Execution header to be imported
-	pub execution_header: ExecutionPayloadHeader,
+	pub execution_header: VersionedExecutionPayloadHeader,
 	/// Merkle proof that execution payload is contained within `header`
 	pub execution_branch: Vec<H256>,
+	/// Roots needed to recompute the execution block hash, which is verified against the one in
+	/// `execution_header`. The transactions root is retained with the execution header.
+	pub execution_block_roots: ExecutionBlockRoots,
 }
 
+/// Roots of the execution block header that execution payload headers only commit to as SSZ
+/// roots, since the execution block header commits to trie roots of the same lists instead.
 #[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo)]
 #[cfg_attr(
 	feature = "std",
 	derive(serde::Deserialize),
 	serde(deny_unknown_fields, bound(serialize = ""), bound(deserialize = ""))
 )]
-pub struct AncestryProof {
-	/// Merkle proof that `header` is an ancestor of `finalized_header`
-	pub header_branch: Vec<H256>,
-	/// Root of a finalized block that has already been imported into the light client
-	pub finalized_block_root: H256,
+pub struct ExecutionBlockRoots {
+	pub transactions_root: H256,
+	pub withdrawals_root: H256,
+	/// EIP-7685 requests hash, which is only committed to by execution blocks from Electra on.
+	#[cfg_attr(feature = "std", serde(default))]
+	pub requests_hash: Option<H256>,
+}
+
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo)]
+#[cfg_attr(
+	feature = "std",
+	derive(serde::Deserialize),
+	serde(
+		deny_unknown_fields,
+		rename_all = "snake_case",
+		bound(serialize = ""),
+		bound(deserialize = "")
+	)
+)]
+pub enum AncestryProof {
+	/// Proves `header` through the `block_roots` of a finalized header that has already been
+	/// imported, for headers within `SLOTS_PER_HISTORICAL_ROOT` slots of it.
+	BlockRoots {
+		/// Merkle proof that `header` is an ancestor of `finalized_header`
+		header_branch: Vec<H256>,
+		/// Root of a finalized block that has already been imported into the light client
+		finalized_block_root: H256,
+	},
+	/// Proves `header` through the `historical_summaries` of a finalized header that has already
+	/// been imported, for headers older than the `block_roots` of any finalized header.
+	HistoricalSummaries {
+		/// Merkle proof that `header` is in the `block_summary_root`
+		header_branch: Vec<H256>,
+		/// Root of the `block_roots` vector of the historical summary covering `header`
+		block_summary_root: H256,
+		/// Merkle proof that `block_summary_root` is in the `historical_summaries` of the
+		/// finalized beacon state, using the finalized header's `state_root` as tree root
+		block_summary_root_branch: Vec<H256>,
+		/// Finalized header that has already been imported into the light client
+		finalized_header: BeaconHeader,
+	},
 }
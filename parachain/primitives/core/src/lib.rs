@@ -11,7 +11,7 @@
 use codec::{Decode, Encode};
 use frame_support::dispatch::DispatchError;
 use scale_info::TypeInfo;
-use snowbridge_ethereum::Log;
+use snowbridge_ethereum::{Account, Log, Transaction, H160, U256};
 use sp_core::{RuntimeDebug, H256};
 use sp_std::vec::Vec;
 
@@ -61,6 +61,45 @@ pub trait Verifier {
 	fn verify(message: &Message) -> Result<Log, DispatchError>;
 }
 
+/// A trait for verifying Ethereum state.
+///
+/// This trait should be implemented by runtime modules that track Ethereum execution headers, so
+/// that other modules can trustlessly read Ethereum account and contract storage state. Proofs are
+/// in the format returned by `eth_getProof`.
+pub trait StateVerifier {
+	/// Verifies an account proof against the state root of the execution block `block_hash`.
+	/// Returns `None` if the account does not exist.
+	fn verify_account(
+		block_hash: H256,
+		address: H160,
+		account_proof: &[Vec<u8>],
+	) -> Result<Option<Account>, DispatchError>;
+
+	/// Verifies the value of the storage slot `key` of a contract in the execution block
+	/// `block_hash`. Unset storage slots are zero.
+	fn verify_storage(
+		block_hash: H256,
+		address: H160,
+		account_proof: &[Vec<u8>],
+		key: H256,
+		storage_proof: &[Vec<u8>],
+	) -> Result<U256, DispatchError>;
+}
+
+/// A trait for verifying the inclusion of Ethereum transactions.
+///
+/// This trait should be implemented by runtime modules that track Ethereum execution headers, so
+/// that other modules can trustlessly inspect the transactions of a block.
+pub trait TransactionVerifier {
+	/// Verifies that `proof` proves the transaction at `tx_index` in the execution block
+	/// `block_hash`, and returns the transaction together with its recovered sender.
+	fn verify_transaction(
+		block_hash: H256,
+		tx_index: u32,
+		proof: &[Vec<u8>],
+	) -> Result<Transaction, DispatchError>;
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, RuntimeDebug)]
 pub enum SubmitError {
 	MessageTooLarge,
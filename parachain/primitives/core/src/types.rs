@@ -4,6 +4,7 @@
 
 use codec::{Decode, Encode};
 use frame_support::{scale_info::TypeInfo, RuntimeDebug};
+use snowbridge_beacon_primitives::ExecutionHeaderUpdate;
 use sp_core::{H160, H256};
 use sp_runtime::DigestItem;
 use sp_std::vec::Vec;
@@ -25,9 +26,8 @@ pub type MessageNonce = u64;
 /// A message relayed from Ethereum.
 #[derive(PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct Message {
-	/// The raw RLP-encoded message data.
-	pub data: Vec<u8>,
-	/// Input to the message verifier
+	/// Input to the message verifier. The message itself is the log at `proof.log_index` in the
+	/// proven receipt.
 	pub proof: Proof,
 }
 
@@ -41,8 +41,13 @@ pub struct Proof {
 	pub block_hash: H256,
 	// The index of the transaction (and receipt) within the block.
 	pub tx_index: u32,
+	// The index of the message log within the receipt.
+	pub log_index: u32,
 	// Proof keys and values
 	pub data: (Vec<Vec<u8>>, Vec<Vec<u8>>),
+	// Proof that the execution header of `block_hash` is finalized, to verify the message without
+	// the execution header having been imported beforehand.
+	pub execution_proof: Option<ExecutionHeaderUpdate>,
 }
 
 /// Auxiliary [`DigestItem`] to include in header digest.
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+use codec::{Decode, Encode};
+use ethereum_types::{H256, U256};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// An account as stored in the Ethereum state trie.
+#[derive(Clone, Default, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct Account {
+	pub nonce: u64,
+	pub balance: U256,
+	/// Root of the account's storage trie.
+	pub storage_root: H256,
+	/// Hash of the account's code.
+	pub code_hash: H256,
+}
+
+impl rlp::Decodable for Account {
+	fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
+		let mut iter = rlp.iter();
+
+		let nonce: u64 = match iter.next() {
+			Some(data) => data.as_val()?,
+			None => return Err(rlp::DecoderError::Custom("Expected account nonce")),
+		};
+
+		let balance: U256 = match iter.next() {
+			Some(data) => data.as_val()?,
+			None => return Err(rlp::DecoderError::Custom("Expected account balance")),
+		};
+
+		let storage_root: H256 = match iter.next() {
+			Some(data) => data.as_val()?,
+			None => return Err(rlp::DecoderError::Custom("Expected account storage root")),
+		};
+
+		let code_hash: H256 = match iter.next() {
+			Some(data) => data.as_val()?,
+			None => return Err(rlp::DecoderError::Custom("Expected account code hash")),
+		};
+
+		Ok(Self { nonce, balance, storage_root, code_hash })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::Account;
+	use hex_literal::hex;
+
+	const RAW_ACCOUNT: [u8; 78] = hex!(
+		"
+		f84c04884563918244f40000a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622f
+		b5e363b421a0c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470
+	"
+	);
+
+	#[test]
+	fn decode_account() {
+		let account: Account = rlp::decode(&RAW_ACCOUNT).unwrap();
+		assert_eq!(account.nonce, 4);
+		assert_eq!(account.balance, 5_000_000_000_000_000_000u128.into());
+		assert_eq!(
+			account.storage_root,
+			hex!("56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421").into()
+		);
+		assert_eq!(
+			account.code_hash,
+			hex!("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470").into()
+		);
+	}
+}
@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+use crate::header::EMPTY_OMMERS_HASH;
+use ethereum_types::{H160, H256, U256};
+use rlp::RlpStream;
+use sp_io::hashing::keccak_256;
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+/// The header of an execution block produced after the merge, from which the execution block hash
+/// is computed. Fields introduced by later forks are `None` for blocks produced before them.
+#[derive(Clone, Default, PartialEq, RuntimeDebug)]
+pub struct ExecutionHeader {
+	pub parent_hash: H256,
+	pub fee_recipient: H160,
+	pub state_root: H256,
+	pub transactions_root: H256,
+	pub receipts_root: H256,
+	pub logs_bloom: Vec<u8>,
+	pub prev_randao: H256,
+	pub block_number: u64,
+	pub gas_limit: u64,
+	pub gas_used: u64,
+	pub timestamp: u64,
+	pub extra_data: Vec<u8>,
+	pub base_fee_per_gas: U256,
+	/// Shanghai (EIP-4895)
+	pub withdrawals_root: Option<H256>,
+	/// Cancun (EIP-4844)
+	pub blob_gas_used: Option<u64>,
+	/// Cancun (EIP-4844)
+	pub excess_blob_gas: Option<u64>,
+	/// Cancun (EIP-4788)
+	pub parent_beacon_block_root: Option<H256>,
+	/// Prague (EIP-7685)
+	pub requests_hash: Option<H256>,
+}
+
+impl ExecutionHeader {
+	/// Computes the execution block hash, the keccak hash of the RLP encoded header.
+	pub fn hash(&self) -> H256 {
+		keccak_256(&rlp::encode(self)).into()
+	}
+}
+
+impl rlp::Encodable for ExecutionHeader {
+	fn rlp_append(&self, s: &mut RlpStream) {
+		let fork_fields: Vec<Vec<u8>> = [
+			self.withdrawals_root.map(|root| rlp::encode(&root)),
+			self.blob_gas_used.map(|gas| rlp::encode(&gas)),
+			self.excess_blob_gas.map(|gas| rlp::encode(&gas)),
+			self.parent_beacon_block_root.map(|root| rlp::encode(&root)),
+			self.requests_hash.map(|hash| rlp::encode(&hash)),
+		]
+		.into_iter()
+		// Fields of a fork are only present if those of all previous forks are too.
+		.map_while(|field| field.map(|field| field.to_vec()))
+		.collect();
+
+		s.begin_list(16 + fork_fields.len());
+		s.append(&self.parent_hash);
+		s.append(&H256(EMPTY_OMMERS_HASH));
+		s.append(&self.fee_recipient);
+		s.append(&self.state_root);
+		s.append(&self.transactions_root);
+		s.append(&self.receipts_root);
+		s.append(&self.logs_bloom);
+		// Proof-of-stake blocks have no difficulty and no nonce.
+		s.append_empty_data();
+		s.append(&self.block_number);
+		s.append(&self.gas_limit);
+		s.append(&self.gas_used);
+		s.append(&self.timestamp);
+		s.append(&self.extra_data);
+		s.append(&self.prev_randao);
+		s.append(&[0u8; 8].as_slice());
+		s.append(&self.base_fee_per_gas);
+		for field in fork_fields.iter() {
+			s.append_raw(field, 1);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use hex_literal::hex;
+
+	fn capella_header() -> ExecutionHeader {
+		ExecutionHeader {
+			parent_hash: hex!("6c9657f1267ad6040ea017ff6d02b55c4ba25cb092b8326d321dd98d01d1ee64")
+				.into(),
+			fee_recipient: H160::zero(),
+			state_root: hex!("01f975f7cdff9b0a8844304aa59062fe18af0fef4636539312dfe20d238600ba")
+				.into(),
+			transactions_root: hex!(
+				"56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+			)
+			.into(),
+			receipts_root: hex!("56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421")
+				.into(),
+			logs_bloom: vec![0; 256],
+			prev_randao: hex!("cdfcab74bc26b3f4311afdc72d2d21d33a4b045187a01fa208a9d687a6d1d25c")
+				.into(),
+			block_number: 3622,
+			gas_limit: 30000000,
+			gas_used: 0,
+			timestamp: 1685722543,
+			extra_data: hex!("d983010b02846765746888676f312e31392e358664617277696e").to_vec(),
+			base_fee_per_gas: U256::from(7u64),
+			withdrawals_root: Some(H256::repeat_byte(0x11)),
+			blob_gas_used: None,
+			excess_blob_gas: None,
+			parent_beacon_block_root: None,
+			requests_hash: None,
+		}
+	}
+
+	#[test]
+	fn compute_capella_block_hash() {
+		let header = capella_header();
+		assert_eq!(
+			header.hash(),
+			hex!("455d91e417e94513cee5978cf6038ac8deb6effb0b8c4253af1a6eea20453d65").into()
+		);
+		assert_eq!(rlp::Rlp::new(&rlp::encode(&header)).item_count(), Ok(17));
+	}
+
+	#[test]
+	fn compute_deneb_block_hash() {
+		let header = ExecutionHeader {
+			parent_hash: hex!("d6c0617d53677f5f4053f017f88689826951654a0d3f1faba0e093ee4e1f22bb")
+				.into(),
+			state_root: hex!("96a83e9ddf745346fafcb0b03d57314623df669ed543c110662b21302a0fae8b")
+				.into(),
+			transactions_root: hex!(
+				"d44d1805243d1b5951ec397067df01f2fc33258f72da9ecb310a9f604d499cd1"
+			)
+			.into(),
+			receipts_root: hex!("62d13e9a073dd4c6ab8cfb9b4d5d8eab8a0ce60a1bd0ed32e6c1d17c3b4cd6d7")
+				.into(),
+			prev_randao: hex!("62e309d4f5119d1f5c783abc20fc1a549efbab546d8d0b25ff1cfd58be524e67")
+				.into(),
+			block_number: 502,
+			gas_limit: 54492273,
+			gas_used: 199644,
+			timestamp: 1710552813,
+			extra_data: hex!("d983010d0b846765746888676f312e32312e368664617277696e").to_vec(),
+			withdrawals_root: Some(
+				hex!("56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421").into(),
+			),
+			blob_gas_used: Some(131072),
+			excess_blob_gas: Some(786432),
+			parent_beacon_block_root: Some(
+				hex!("adeca22a88799061c48eb4bdaabefa7fd0ef2a9720819d438bfaea88407dd511").into(),
+			),
+			..capella_header()
+		};
+		assert_eq!(
+			header.hash(),
+			hex!("543535c639f73d1f7e8af551384016f5afadc71ac26a6920ce89921bb693b567").into()
+		);
+		assert_eq!(rlp::Rlp::new(&rlp::encode(&header)).item_count(), Ok(20));
+	}
+
+	#[test]
+	fn prague_header_encodes_requests_hash() {
+		let header = ExecutionHeader {
+			blob_gas_used: Some(0),
+			excess_blob_gas: Some(0),
+			parent_beacon_block_root: Some(H256::zero()),
+			requests_hash: Some(H256::repeat_byte(0x22)),
+			..capella_header()
+		};
+		let encoded = rlp::encode(&header);
+		let rlp = rlp::Rlp::new(&encoded);
+		assert_eq!(rlp.item_count(), Ok(21));
+		assert_eq!(rlp.val_at::<H256>(20), Ok(H256::repeat_byte(0x22)));
+	}
+
+	#[test]
+	fn fork_fields_must_be_contiguous() {
+		// The blob fields are not encoded without a withdrawals root
+		let header = ExecutionHeader {
+			withdrawals_root: None,
+			blob_gas_used: Some(0),
+			excess_blob_gas: Some(0),
+			parent_beacon_block_root: Some(H256::zero()),
+			..capella_header()
+		};
+		assert_eq!(rlp::Rlp::new(&rlp::encode(&header)).item_count(), Ok(16));
+	}
+}
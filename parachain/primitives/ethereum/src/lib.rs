@@ -2,16 +2,22 @@
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod account;
+pub mod execution_header;
 pub mod header;
 pub mod log;
 pub mod mpt;
 pub mod receipt;
+pub mod transaction;
 
 pub use ethereum_types::{Address, H160, H256, H64, U256};
 
+pub use account::Account;
+pub use execution_header::ExecutionHeader;
 pub use header::{Bloom, Header, HeaderId};
 pub use log::Log;
 pub use receipt::Receipt;
+pub use transaction::Transaction;
 
 #[derive(Debug)]
 pub enum DecodeError {
@@ -37,6 +37,15 @@ impl rlp::Decodable for Log {
 	}
 }
 
+impl rlp::Encodable for Log {
+	fn rlp_append(&self, s: &mut rlp::RlpStream) {
+		s.begin_list(3);
+		s.append(&self.address);
+		s.append_list(&self.topics);
+		s.append(&self.data);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -73,4 +82,10 @@ mod tests {
 			hex!["266413be5700ce8dd5ac6b9a7dfbabe99b3e45cae9a68ac2757858710b401a38"]
 		);
 	}
+
+	#[test]
+	fn encode_log() {
+		let log: Log = rlp::decode(&RAW_LOG).unwrap();
+		assert_eq!(rlp::encode(&log), RAW_LOG.as_slice());
+	}
 }
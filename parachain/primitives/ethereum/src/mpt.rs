@@ -1,33 +1,137 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
-//! Helper types to work with Ethereum's Merkle Patricia Trie nodes
+//! Merkle Patricia Trie proof verification and helper types to work with trie nodes
 
 use ethereum_types::H256;
-use sp_std::{convert::TryFrom, prelude::*};
+use hex_literal::hex;
+use sp_io::hashing::keccak_256;
+use sp_std::prelude::*;
 
-pub trait Node {
-	fn contains_hash(&self, hash: H256) -> bool;
+/// Root of an empty trie, i.e. the hash of the RLP encoded empty string.
+pub const EMPTY_TRIE_ROOT: H256 =
+	H256(hex!("56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"));
+
+#[derive(Debug, PartialEq)]
+pub enum ProofError {
+	// A proof node is not a valid trie node
+	InvalidNode(rlp::DecoderError),
+	// The proof ends before the key path is resolved
+	MissingNode,
+	// A proof node does not match the hash referenced by its parent
+	HashMismatch,
 }
 
-impl TryFrom<&[u8]> for Box<dyn Node> {
-	type Error = rlp::DecoderError;
+impl From<rlp::DecoderError> for ProofError {
+	fn from(err: rlp::DecoderError) -> Self {
+		ProofError::InvalidNode(err)
+	}
+}
 
-	fn try_from(bytes: &[u8]) -> Result<Box<dyn Node>, Self::Error> {
-		let rlp = rlp::Rlp::new(bytes);
-		match rlp.item_count()? {
-			2 => {
-				let node: ShortNode = rlp.as_val()?;
-				Ok(Box::new(node))
+/// Verifies a Merkle Patricia Trie proof for `key` against `root`, following the key path
+/// through branch, extension and leaf nodes. The proof lists the nodes on the path starting
+/// from the root, as returned by `eth_getProof`. Returns the value stored at `key`, or `None` if
+/// the proof shows that the trie does not contain `key`.
+pub fn verify_proof(
+	root: H256,
+	key: &[u8],
+	proof: &[Vec<u8>],
+) -> Result<Option<Vec<u8>>, ProofError> {
+	if root == EMPTY_TRIE_ROOT && proof.is_empty() {
+		return Ok(None)
+	}
+
+	let nibbles = to_nibbles(key);
+	let mut path = nibbles.as_slice();
+	let mut nodes = proof.iter();
+	let mut next = NodeRef::Hash(root);
+	loop {
+		let node: &[u8] = match next {
+			NodeRef::Hash(hash) => {
+				let node = nodes.next().ok_or(ProofError::MissingNode)?;
+				if keccak_256(node) != hash.0 {
+					return Err(ProofError::HashMismatch)
+				}
+				node
 			},
+			NodeRef::Inline(node) => node,
+		};
+
+		let rlp = rlp::Rlp::new(node);
+		match rlp.item_count()? {
 			17 => {
-				let node: FullNode = rlp.as_val()?;
-				Ok(Box::new(node))
+				let (nibble, rest) = match path.split_first() {
+					Some(split) => split,
+					None => return Ok(non_empty(rlp.at(16)?.data()?)),
+				};
+				path = rest;
+				next = match child_ref(rlp.at(*nibble as usize)?)? {
+					Some(child) => child,
+					None => return Ok(None),
+				};
+			},
+			2 => {
+				let (partial_path, is_leaf) = decode_path(rlp.at(0)?.data()?)?;
+				if is_leaf {
+					if path != partial_path.as_slice() {
+						return Ok(None)
+					}
+					return Ok(Some(rlp.at(1)?.data()?.to_vec()))
+				}
+				path = match path.strip_prefix(partial_path.as_slice()) {
+					Some(rest) => rest,
+					None => return Ok(None),
+				};
+				next = child_ref(rlp.at(1)?)?
+					.ok_or(rlp::DecoderError::Custom("Expected extension node child"))?;
 			},
-			_ => Err(rlp::DecoderError::Custom("Invalid number of list elements")),
+			_ => return Err(rlp::DecoderError::Custom("Invalid number of list elements").into()),
 		}
 	}
 }
 
+/// Reference from a trie node to a child node. Nodes with an encoding shorter than 32 bytes are
+/// embedded in their parent instead of being referenced by hash.
+enum NodeRef<'a> {
+	Hash(H256),
+	Inline(&'a [u8]),
+}
+
+fn child_ref<'a>(item: rlp::Rlp<'a>) -> Result<Option<NodeRef<'a>>, rlp::DecoderError> {
+	if item.is_list() {
+		return Ok(Some(NodeRef::Inline(item.as_raw())))
+	}
+	let data = item.data()?;
+	match data.len() {
+		0 => Ok(None),
+		32 => Ok(Some(NodeRef::Hash(H256::from_slice(data)))),
+		_ => Err(rlp::DecoderError::Custom("Expected 32-byte hash, embedded node or empty child")),
+	}
+}
+
+/// Decodes the hex-prefix encoded path of a leaf or extension node. Returns the path nibbles and
+/// whether the node is a leaf.
+fn decode_path(encoded: &[u8]) -> Result<(Vec<u8>, bool), rlp::DecoderError> {
+	let nibbles = to_nibbles(encoded);
+	let (flag, rest) =
+		nibbles.split_first().ok_or(rlp::DecoderError::Custom("Expected node path"))?;
+	let is_leaf = match flag {
+		0 | 1 => false,
+		2 | 3 => true,
+		_ => return Err(rlp::DecoderError::Custom("Invalid node path prefix")),
+	};
+	// Paths with an even number of nibbles are padded with a zero nibble after the prefix.
+	let path = if flag % 2 == 1 { rest } else { &rest[1..] };
+	Ok((path.to_vec(), is_leaf))
+}
+
+fn to_nibbles(bytes: &[u8]) -> Vec<u8> {
+	bytes.iter().flat_map(|byte| [byte >> 4, byte & 0x0f]).collect()
+}
+
+fn non_empty(data: &[u8]) -> Option<Vec<u8>> {
+	(!data.is_empty()).then(|| data.to_vec())
+}
+
 /// Intermediate trie node with children (refers to node with same name in Geth).
 /// This struct only handles the proof representation, i.e. a child is either empty
 /// or a 32-byte hash of its subtree.
@@ -57,12 +161,6 @@ impl rlp::Decodable for FullNode {
 	}
 }
 
-impl Node for FullNode {
-	fn contains_hash(&self, hash: H256) -> bool {
-		self.children.iter().find(|&h| Some(hash) == *h).is_some()
-	}
-}
-
 /// Trie node where `value` is either the RLP-encoded item we're
 /// proving or an intermediate hash (refers to node with same name in Geth)
 /// Proof verification should return `value`. `key` is an implementation
@@ -90,17 +188,13 @@ impl rlp::Decodable for ShortNode {
 	}
 }
 
-impl Node for ShortNode {
-	fn contains_hash(&self, hash: H256) -> bool {
-		self.value == hash.0
-	}
-}
-
 #[cfg(test)]
 mod tests {
 
 	use super::*;
 	use hex_literal::hex;
+	use rand::{thread_rng, Rng};
+	use snowbridge_testutils::state_proof::*;
 
 	const RAW_PROOF: [&[u8]; 3] = [
         &hex!("f90131a0b5ba404eb5a6a88e56579f4d37ef9813b5ad7f86f0823ff3b407ac5a6bb465eca0398ead2655e78e03c127ce22c5830e90f18b1601ec055f938336c084feb915a9a026d322c26e46c50942c1aabde50e36df5cde572aed650ce73ea3182c6e90a02ca00600a356135f4db1db0d9842264cdff2652676f881669e91e316c0b6dd783011a0837f1deb4075336da320388c1edfffc56c448a43f4a5ba031300d32a7b509fc5a01c3ac82fd65b4aba7f9afaf604d9c82ec7e2deb573a091ae235751bc5c0c288da05d454159d9071b0f68b6e0503d290f23ac7602c1db0c569dee4605d8f5298f09a00bbed10350ec954448df795f6fd46e3faefc800ede061b3840eedc6e2b07a74da0acb02d26a3650f2064c14a435fdf1f668d8655daf455ebdf671713a7c089b3898080808080808080"),
@@ -108,6 +202,267 @@ mod tests {
         &hex!("f904de20b904daf904d701830652f0b9010004200000000000000000000080020000000000010000000000010000000000000000000000000000000000000000000002000000080000000000000000200000000000000000000000000008000000220000000000400010000000000000000000000000000000000000000000000000000000000000040000000010000100000000000800000000004000000000000000000000000000080000004000000000020000000000020000000000000000000000000000000000000000000004000000000002000000000100000000000000000000000000001000000002000020000010200000000000010000000000000000000000000000000000000010000000f903ccf89b9421130f34829b4c343142047a28ce96ec07814b15f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa00000000000000000000000007d843005c7433c16b27ff939cb37471541561ebda0000000000000000000000000e9c1281aae66801fa35ec404d5f2aea393ff6988a000000000000000000000000000000000000000000000000000000005d09b7380f89b9421130f34829b4c343142047a28ce96ec07814b15f863a08c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925a00000000000000000000000007d843005c7433c16b27ff939cb37471541561ebda00000000000000000000000007a250d5630b4cf539739df2c5dacb4c659f2488da0ffffffffffffffffffffffffffffffffffffffffffffffffffffffcc840c6920f89b94c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa0000000000000000000000000e9c1281aae66801fa35ec404d5f2aea393ff6988a00000000000000000000000007a250d5630b4cf539739df2c5dacb4c659f2488da000000000000000000000000000000000000000000000000003e973b5a5d1078ef87994e9c1281aae66801fa35ec404d5f2aea393ff6988e1a01c411e9a96e071241c2f21f7726b17ae89e3cab4c78be50e062b03a9fffbbad1b840000000000000000000000000000000000000000000000000000001f1420ad1d40000000000000000000000000000000000000000000000014ad400879d159a38f8fc94e9c1281aae66801fa35ec404d5f2aea393ff6988f863a0d78ad95fa46c994b6551d0da85fc275fe613ce37657fb8d5e3d130840159d822a00000000000000000000000007a250d5630b4cf539739df2c5dacb4c659f2488da00000000000000000000000007a250d5630b4cf539739df2c5dacb4c659f2488db88000000000000000000000000000000000000000000000000000000005d415f3320000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000003e973b5a5d1078ef87a94c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2f842a07fcf532c15f0a6db0bd6d0e038bea71d30d808c7d98cb3bf7268a95bf5081b65a00000000000000000000000007a250d5630b4cf539739df2c5dacb4c659f2488da000000000000000000000000000000000000000000000000003e973b5a5d1078e"),
     ];
 
+	#[test]
+	fn verify_account_proof() {
+		let value = verify_proof(STATE_ROOT.into(), &ACCOUNT_KEY, &account_proof()).unwrap();
+		let account: crate::Account = rlp::decode(&value.unwrap()).unwrap();
+		assert_eq!(account.nonce, 1);
+		assert_eq!(account.storage_root, STORAGE_ROOT.into());
+	}
+
+	#[test]
+	fn verify_storage_proof() {
+		let value = verify_proof(STORAGE_ROOT.into(), &STORAGE_KEY, &storage_proof()).unwrap();
+		assert_eq!(
+			value,
+			Some(hex!("9a0100000000000000000000000000000000000000000000000007").to_vec())
+		);
+	}
+
+	#[test]
+	fn verify_exclusion_proofs() {
+		// The key path ends in a leaf for a different key
+		let proof = vec![ACCOUNT_PROOF_0.to_vec(), ABSENT_ACCOUNT_PROOF_1.to_vec()];
+		assert_eq!(verify_proof(STATE_ROOT.into(), &ABSENT_ACCOUNT_KEY, &proof), Ok(None));
+
+		// The key path ends in an empty branch child
+		let proof = vec![STORAGE_PROOF_0.to_vec()];
+		assert_eq!(verify_proof(STORAGE_ROOT.into(), &ABSENT_STORAGE_KEY, &proof), Ok(None));
+
+		assert_eq!(verify_proof(EMPTY_TRIE_ROOT, &STORAGE_KEY, &[]), Ok(None));
+	}
+
+	#[test]
+	fn verify_proof_for_other_key_fails() {
+		// A valid proof for one key does not prove the value of another key
+		assert_eq!(
+			verify_proof(STATE_ROOT.into(), &ABSENT_ACCOUNT_KEY, &account_proof()),
+			Err(ProofError::HashMismatch)
+		);
+	}
+
+	#[test]
+	fn verify_invalid_proofs() {
+		let mut proof = account_proof();
+		proof[2][40] ^= 1;
+		assert_eq!(
+			verify_proof(STATE_ROOT.into(), &ACCOUNT_KEY, &proof),
+			Err(ProofError::HashMismatch)
+		);
+
+		let mut proof = account_proof();
+		proof.pop();
+		assert_eq!(
+			verify_proof(STATE_ROOT.into(), &ACCOUNT_KEY, &proof),
+			Err(ProofError::MissingNode)
+		);
+
+		assert_eq!(
+			verify_proof(STORAGE_ROOT.into(), &ACCOUNT_KEY, &account_proof()),
+			Err(ProofError::HashMismatch)
+		);
+	}
+
+	#[test]
+	fn verify_receipt_proof() {
+		let root: H256 = keccak_256(RAW_PROOF[0]).into();
+		let proof: Vec<Vec<u8>> = RAW_PROOF.iter().map(|node| node.to_vec()).collect();
+		let receipt = verify_proof(root, &rlp::encode(&5u32), &proof).unwrap().unwrap();
+		assert_eq!(&receipt[..], &RAW_PROOF[2][7..]);
+
+		// The proof is bound to the transaction index
+		assert_eq!(verify_proof(root, &rlp::encode(&6u32), &proof), Err(ProofError::HashMismatch));
+		assert_eq!(verify_proof(root, &rlp::encode(&0u32), &proof), Err(ProofError::HashMismatch));
+		assert_eq!(
+			verify_proof(root, &rlp::encode(&5u32), &proof[..2]),
+			Err(ProofError::MissingNode)
+		);
+	}
+
+	#[test]
+	fn reference_trie_matches_known_roots() {
+		let items = |pairs: &[(&str, &str)]| -> Vec<(Vec<u8>, Vec<u8>)> {
+			pairs
+				.iter()
+				.map(|(k, v)| (k.as_bytes().to_vec(), v.as_bytes().to_vec()))
+				.collect()
+		};
+
+		let dogs = items(&[("doe", "reindeer"), ("dog", "puppy"), ("dogglesworth", "cat")]);
+		let (root, proof) = build_trie(&dogs, b"dogglesworth");
+		assert_eq!(
+			root,
+			hex!("8aad789dff2f538bca5d8ea56e8abe10f4c7ba3a5dea95fea4cd6e7c3a1168d3").into()
+		);
+		assert_eq!(verify_proof(root, b"dogglesworth", &proof), Ok(Some(b"cat".to_vec())));
+
+		// "do" is stored in a branch node, as it is a prefix of the other keys
+		let horses =
+			items(&[("do", "verb"), ("dog", "puppy"), ("doge", "coin"), ("horse", "stallion")]);
+		let (root, proof) = build_trie(&horses, b"do");
+		assert_eq!(
+			root,
+			hex!("5991bb8c6514148a29db676a14ac506cd2cd5775ace63c30a4fe457715e9ac84").into()
+		);
+		assert_eq!(verify_proof(root, b"do", &proof), Ok(Some(b"verb".to_vec())));
+		let (_, proof) = build_trie(&horses, b"dot");
+		assert_eq!(verify_proof(root, b"dot", &proof), Ok(None));
+	}
+
+	#[test]
+	fn verify_proofs_of_random_receipt_tries() {
+		let mut rng = thread_rng();
+		for _ in 0..50 {
+			let count: u32 = rng.gen_range(1..300);
+			let items: Vec<_> = (0..count)
+				.map(|index| (rlp::encode(&index).to_vec(), random_value(index)))
+				.collect();
+			check_proofs(&items, |rng| rlp::encode(&(count + rng.gen_range(0..10u32))).to_vec());
+		}
+	}
+
+	#[test]
+	fn verify_proofs_of_random_state_tries() {
+		let mut rng = thread_rng();
+		for _ in 0..50 {
+			let count: u32 = rng.gen_range(1..100);
+			let items: Vec<_> = (0..count)
+				.map(|index| (rng.gen::<[u8; 32]>().to_vec(), random_value(index)))
+				.collect();
+			check_proofs(&items, |rng| rng.gen::<[u8; 32]>().to_vec());
+		}
+	}
+
+	/// Checks that every sampled key is proven to hold its value, that proofs are not valid for
+	/// other keys, and that keys from `absent_key` are proven absent.
+	fn check_proofs(
+		items: &[(Vec<u8>, Vec<u8>)],
+		absent_key: impl Fn(&mut rand::rngs::ThreadRng) -> Vec<u8>,
+	) {
+		let mut rng = thread_rng();
+		for _ in 0..10 {
+			let (key, value) = &items[rng.gen_range(0..items.len())];
+			let (root, proof) = build_trie(items, key);
+			assert_eq!(verify_proof(root, key, &proof), Ok(Some(value.clone())));
+
+			let (other_key, _) = &items[rng.gen_range(0..items.len())];
+			if other_key != key {
+				assert_ne!(verify_proof(root, other_key, &proof), Ok(Some(value.clone())));
+			}
+
+			let key = absent_key(&mut rng);
+			let (_, proof) = build_trie(items, &key);
+			assert_eq!(verify_proof(root, &key, &proof), Ok(None));
+		}
+	}
+
+	/// Random value which is unique for `index`.
+	fn random_value(index: u32) -> Vec<u8> {
+		let mut rng = thread_rng();
+		let len = rng.gen_range(0..100);
+		let mut value = index.to_be_bytes().to_vec();
+		value.extend((0..len).map(|_| rng.gen::<u8>()));
+		value
+	}
+
+	/// Reference trie builder. Returns the root of the trie over `items` and the proof for `key`.
+	fn build_trie(items: &[(Vec<u8>, Vec<u8>)], key: &[u8]) -> (H256, Vec<Vec<u8>>) {
+		let items: Vec<_> =
+			items.iter().map(|(key, value)| (to_nibbles(key), value.clone())).collect();
+		let mut proof = vec![];
+		let root = encode_node(&items, Some(&to_nibbles(key)), &mut proof);
+		proof.push(root.clone());
+		// Nodes are collected from the deepest one up
+		proof.reverse();
+		(keccak_256(&root).into(), proof)
+	}
+
+	/// Encodes the trie node over `items`, given as nibble paths and values. Collects the hashed
+	/// nodes on the remaining path to `key` into `proof`.
+	fn encode_node(
+		items: &[(Vec<u8>, Vec<u8>)],
+		key: Option<&[u8]>,
+		proof: &mut Vec<Vec<u8>>,
+	) -> Vec<u8> {
+		let mut stream = rlp::RlpStream::new();
+		if let [(path, value)] = items {
+			stream.begin_list(2);
+			stream.append(&encode_path(path, true));
+			stream.append(value);
+			return stream.out().to_vec()
+		}
+
+		let first = &items[0].0;
+		let prefix_len = items[1..]
+			.iter()
+			.map(|(path, _)| first.iter().zip(path).take_while(|(a, b)| a == b).count())
+			.min()
+			.unwrap_or_default();
+		if prefix_len > 0 {
+			let prefix = &first[..prefix_len];
+			let children: Vec<_> = items
+				.iter()
+				.map(|(path, value)| (path[prefix_len..].to_vec(), value.clone()))
+				.collect();
+			stream.begin_list(2);
+			stream.append(&encode_path(prefix, false));
+			append_child(
+				&mut stream,
+				&children,
+				key.and_then(|key| key.strip_prefix(prefix)),
+				proof,
+			);
+			return stream.out().to_vec()
+		}
+
+		stream.begin_list(17);
+		for nibble in 0..16u8 {
+			let children: Vec<_> = items
+				.iter()
+				.filter(|(path, _)| path.first() == Some(&nibble))
+				.map(|(path, value)| (path[1..].to_vec(), value.clone()))
+				.collect();
+			if children.is_empty() {
+				stream.append_empty_data();
+				continue
+			}
+			let child_key = key
+				.and_then(|key| key.split_first())
+				.filter(|(first, _)| **first == nibble)
+				.map(|(_, rest)| rest);
+			append_child(&mut stream, &children, child_key, proof);
+		}
+		match items.iter().find(|(path, _)| path.is_empty()) {
+			Some((_, value)) => stream.append(value),
+			None => stream.append_empty_data(),
+		};
+		stream.out().to_vec()
+	}
+
+	fn append_child(
+		stream: &mut rlp::RlpStream,
+		items: &[(Vec<u8>, Vec<u8>)],
+		key: Option<&[u8]>,
+		proof: &mut Vec<Vec<u8>>,
+	) {
+		let node = encode_node(items, key, proof);
+		if node.len() < 32 {
+			stream.append_raw(&node, 1);
+		} else {
+			if key.is_some() {
+				proof.push(node.clone());
+			}
+			stream.append(&keccak_256(&node).to_vec());
+		}
+	}
+
+	fn encode_path(path: &[u8], is_leaf: bool) -> Vec<u8> {
+		let flag = if is_leaf { 2 } else { 0 };
+		let mut nibbles = if path.len() % 2 == 1 { vec![flag + 1] } else { vec![flag, 0] };
+		nibbles.extend_from_slice(path);
+		nibbles.chunks(2).map(|pair| pair[0] << 4 | pair[1]).collect()
+	}
+
 	#[test]
 	fn decode_full_node() {
 		let node1: FullNode = rlp::decode(RAW_PROOF[0]).unwrap();
@@ -6,8 +6,14 @@ use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
 use sp_std::prelude::*;
 
+/// Transaction types are in the range `[0, 0x7f]`, which cannot be confused with the first byte
+/// of an RLP list (EIP-2718).
+pub(crate) const MAX_TX_TYPE: u8 = 0x7f;
+
 #[derive(Clone, Default, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct Receipt {
+	/// The EIP-2718 type of the transaction, or zero for legacy receipts.
+	pub tx_type: u8,
 	pub post_state_or_status: Vec<u8>,
 	pub cumulative_gas_used: u64,
 	pub bloom: Bloom,
@@ -19,7 +25,14 @@ impl Receipt {
 		self.logs.iter().find(|&l| l == log).is_some()
 	}
 
-	fn decode_list(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
+	/// Whether the transaction succeeded, according to the status of the receipt (EIP-658).
+	/// Receipts from before the Byzantium fork carry an intermediate state root instead of a
+	/// status, and are never considered successful.
+	pub fn is_success(&self) -> bool {
+		self.post_state_or_status == [1]
+	}
+
+	fn decode_list(tx_type: u8, rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
 		let mut iter = rlp.iter();
 
 		let post_state_or_status: Vec<u8> = match iter.next() {
@@ -42,39 +55,59 @@ impl Receipt {
 			None => return Err(rlp::DecoderError::Custom("Expected receipt logs")),
 		};
 
-		Ok(Self { post_state_or_status, cumulative_gas_used, bloom, logs })
+		Ok(Self { tx_type, post_state_or_status, cumulative_gas_used, bloom, logs })
 	}
 }
 
 impl rlp::Decodable for Receipt {
 	fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
 		if rlp.is_data() {
-			// Typed receipt
+			// Typed receipt (EIP-2718). All types so far, including EIP-2930 (1), EIP-1559 (2)
+			// and EIP-4844 (3), share the payload of legacy receipts.
 			let data = rlp.as_raw();
-			match data[0] {
-				// 1 = EIP-2930, 2 = EIP-1559
-				1 | 2 => {
+			match data.first() {
+				Some(&tx_type) if (1..=MAX_TX_TYPE).contains(&tx_type) => {
 					let receipt_rlp = &rlp::Rlp::new(&data[1..]);
 					if !receipt_rlp.is_list() {
 						return Err(rlp::DecoderError::RlpExpectedToBeList)
 					}
-					Self::decode_list(&rlp::Rlp::new(&data[1..]))
+					Self::decode_list(tx_type, receipt_rlp)
 				},
 				_ => Err(rlp::DecoderError::Custom("Unsupported receipt type")),
 			}
 		} else if rlp.is_list() {
 			// Legacy receipt
-			Self::decode_list(rlp)
+			Self::decode_list(0, rlp)
 		} else {
 			Err(rlp::DecoderError::RlpExpectedToBeList)
 		}
 	}
 }
 
+impl rlp::Encodable for Receipt {
+	/// Typed receipts are encoded as `tx_type || rlp(payload)`, which is how they are stored
+	/// in the receipts trie.
+	fn rlp_append(&self, s: &mut rlp::RlpStream) {
+		let mut stream = rlp::RlpStream::new_list(4);
+		stream.append(&self.post_state_or_status);
+		stream.append(&self.cumulative_gas_used);
+		stream.append(&self.bloom);
+		stream.append_list(&self.logs);
+		let payload = stream.out();
+
+		if self.tx_type == 0 {
+			s.append_raw(&payload, 1);
+		} else {
+			s.append_raw(&[&[self.tx_type], payload.as_ref()].concat(), 1);
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 
 	use super::Receipt;
+	use crate::Log;
 	use hex_literal::hex;
 
 	const RAW_RECEIPT: [u8; 1242] = hex!(
@@ -136,5 +169,64 @@ mod tests {
 				.into(),
 		);
 		assert_eq!(receipt.logs.len(), 6);
+		assert!(receipt.is_success());
+		assert_eq!(receipt.tx_type, 0);
+	}
+
+	#[test]
+	fn encode_legacy_receipt() {
+		let receipt: Receipt = rlp::decode(&RAW_RECEIPT).unwrap();
+		assert_eq!(rlp::encode(&receipt), RAW_RECEIPT.as_slice());
+	}
+
+	fn receipt(tx_type: u8, status: &[u8]) -> Receipt {
+		let log: Log = rlp::Rlp::new(&RAW_RECEIPT).at(3).unwrap().val_at(0).unwrap();
+		Receipt {
+			tx_type,
+			post_state_or_status: status.to_vec(),
+			cumulative_gas_used: 21000,
+			bloom: Default::default(),
+			logs: vec![log],
+		}
+	}
+
+	#[test]
+	fn encode_typed_receipts() {
+		// EIP-2930, EIP-1559, EIP-4844 and a future transaction type
+		for tx_type in [1, 2, 3, 0x7f] {
+			let receipt = receipt(tx_type, &[1]);
+			let encoded = rlp::encode(&receipt);
+			assert_eq!(encoded[0], tx_type);
+			assert_eq!(rlp::decode::<Receipt>(&encoded), Ok(receipt));
+		}
+	}
+
+	#[test]
+	fn decode_unsupported_receipt_type() {
+		let encoded = rlp::encode(&receipt(0, &[1]));
+		for tx_type in [0, 0x80] {
+			assert_eq!(
+				rlp::decode::<Receipt>(&[&[tx_type], encoded.as_ref()].concat()),
+				Err(rlp::DecoderError::Custom("Unsupported receipt type"))
+			);
+		}
+		assert!(rlp::decode::<Receipt>(&[]).is_err());
+	}
+
+	#[test]
+	fn decode_receipt_status() {
+		// Legacy, EIP-2930, EIP-1559 and EIP-4844 receipts
+		for tx_type in [0, 1, 2, 3] {
+			let encoded = rlp::encode(&receipt(tx_type, &[1]));
+			assert!(rlp::decode::<Receipt>(&encoded).unwrap().is_success());
+
+			// Failed transactions have an empty status
+			let encoded = rlp::encode(&receipt(tx_type, &[]));
+			assert!(!rlp::decode::<Receipt>(&encoded).unwrap().is_success());
+		}
+
+		// Pre-Byzantium receipts carry a state root
+		let encoded = rlp::encode(&receipt(0, &[7u8; 32]));
+		assert!(!rlp::decode::<Receipt>(&encoded).unwrap().is_success());
 	}
 }
@@ -0,0 +1,290 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+use crate::{receipt::MAX_TX_TYPE, H160, U256};
+use codec::{Decode, Encode};
+use rlp::{DecoderError, Rlp, RlpStream};
+use scale_info::TypeInfo;
+use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+/// A signed transaction, together with the sender recovered from its signature.
+#[derive(Clone, Default, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct Transaction {
+	/// The EIP-2718 type of the transaction, or zero for legacy transactions.
+	pub tx_type: u8,
+	/// The chain ID, or `None` for legacy transactions without replay protection (EIP-155).
+	pub chain_id: Option<u64>,
+	pub nonce: u64,
+	pub gas_limit: u64,
+	/// The recipient, or `None` for contract creations.
+	pub to: Option<H160>,
+	pub value: U256,
+	pub input: Vec<u8>,
+	pub sender: H160,
+}
+
+impl Transaction {
+	fn decode_legacy(rlp: &Rlp) -> Result<Self, DecoderError> {
+		if rlp.item_count()? != 9 {
+			return Err(DecoderError::RlpIncorrectListLen)
+		}
+
+		let v: u64 = rlp.val_at(6)?;
+		let (chain_id, recovery_id) = match v {
+			27 | 28 => (None, v - 27),
+			// EIP-155
+			v if v >= 35 => (Some((v - 35) / 2), (v - 35) % 2),
+			_ => return Err(DecoderError::Custom("Invalid transaction signature")),
+		};
+
+		// The signature covers the transaction fields, followed by the chain ID and two empty
+		// fields for replay protected transactions.
+		let mut stream = RlpStream::new_list(if chain_id.is_some() { 9 } else { 6 });
+		for index in 0..6 {
+			stream.append_raw(rlp.at(index)?.as_raw(), 1);
+		}
+		if let Some(chain_id) = chain_id {
+			stream.append(&chain_id);
+			stream.append_empty_data();
+			stream.append_empty_data();
+		}
+		let message = keccak_256(&stream.out());
+
+		Ok(Self {
+			tx_type: 0,
+			chain_id,
+			nonce: rlp.val_at(0)?,
+			gas_limit: rlp.val_at(2)?,
+			to: decode_to(&rlp.at(3)?)?,
+			value: rlp.val_at(4)?,
+			input: rlp.val_at(5)?,
+			sender: recover_sender(&message, recovery_id as u8, rlp.val_at(7)?, rlp.val_at(8)?)?,
+		})
+	}
+
+	fn decode_typed(tx_type: u8, rlp: &Rlp) -> Result<Self, DecoderError> {
+		// EIP-2930 transactions have a single gas price, later types a priority fee and a max fee.
+		let (item_count, gas_limit_index) = match tx_type {
+			1 => (11, 3),
+			2 => (12, 4),
+			3 => (14, 4),
+			_ => return Err(DecoderError::Custom("Unsupported transaction type")),
+		};
+		if rlp.item_count()? != item_count {
+			return Err(DecoderError::RlpIncorrectListLen)
+		}
+
+		// The signature, which is made up of the last three fields, covers the type and all other
+		// fields.
+		let signature_index = item_count - 3;
+		let mut stream = RlpStream::new_list(signature_index);
+		for index in 0..signature_index {
+			stream.append_raw(rlp.at(index)?.as_raw(), 1);
+		}
+		let message = keccak_256(&[&[tx_type], stream.out().as_ref()].concat());
+
+		let y_parity: u8 = rlp.val_at(signature_index)?;
+		if y_parity > 1 {
+			return Err(DecoderError::Custom("Invalid transaction signature"))
+		}
+
+		Ok(Self {
+			tx_type,
+			chain_id: Some(rlp.val_at(0)?),
+			nonce: rlp.val_at(1)?,
+			gas_limit: rlp.val_at(gas_limit_index)?,
+			to: decode_to(&rlp.at(gas_limit_index + 1)?)?,
+			value: rlp.val_at(gas_limit_index + 2)?,
+			input: rlp.val_at(gas_limit_index + 3)?,
+			sender: recover_sender(
+				&message,
+				y_parity,
+				rlp.val_at(signature_index + 1)?,
+				rlp.val_at(signature_index + 2)?,
+			)?,
+		})
+	}
+}
+
+impl rlp::Decodable for Transaction {
+	fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+		if rlp.is_data() {
+			// Typed transaction (EIP-2718)
+			let data = rlp.as_raw();
+			match data.first() {
+				Some(&tx_type) if (1..=MAX_TX_TYPE).contains(&tx_type) => {
+					let transaction_rlp = &Rlp::new(&data[1..]);
+					if !transaction_rlp.is_list() {
+						return Err(DecoderError::RlpExpectedToBeList)
+					}
+					Self::decode_typed(tx_type, transaction_rlp)
+				},
+				_ => Err(DecoderError::Custom("Unsupported transaction type")),
+			}
+		} else if rlp.is_list() {
+			// Legacy transaction
+			Self::decode_legacy(rlp)
+		} else {
+			Err(DecoderError::RlpExpectedToBeList)
+		}
+	}
+}
+
+fn decode_to(rlp: &Rlp) -> Result<Option<H160>, DecoderError> {
+	let data = rlp.data()?;
+	match data.len() {
+		0 => Ok(None),
+		20 => Ok(Some(H160::from_slice(data))),
+		_ => Err(DecoderError::RlpInvalidLength),
+	}
+}
+
+fn recover_sender(
+	message: &[u8; 32],
+	recovery_id: u8,
+	r: U256,
+	s: U256,
+) -> Result<H160, DecoderError> {
+	let mut signature = [0u8; 65];
+	r.to_big_endian(&mut signature[0..32]);
+	s.to_big_endian(&mut signature[32..64]);
+	signature[64] = recovery_id;
+
+	let public = secp256k1_ecdsa_recover(&signature, message)
+		.map_err(|_| DecoderError::Custom("Invalid transaction signature"))?;
+	Ok(H160::from_slice(&keccak_256(&public)[12..]))
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::Transaction;
+	use crate::{H160, U256};
+	use hex_literal::hex;
+
+	// All transactions are signed with the private key 0x4646..46.
+	const SENDER: [u8; 20] = hex!("9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f");
+	const RECIPIENT: [u8; 20] = hex!("3535353535353535353535353535353535353535");
+
+	// The example transaction of EIP-155
+	const RAW_LEGACY_TRANSACTION: [u8; 110] = hex!(
+		"
+		f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764
+		00008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cb
+		e9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83
+	"
+	);
+
+	// A contract creation without replay protection
+	const RAW_UNPROTECTED_TRANSACTION: [u8; 85] = hex!(
+		"
+		f8530d8504a817c800830186a080808260001ca071550e6c83a9381f35c568d1a80e11fa3e0efc97
+		dfd0e0f17492a2edb64c37a9a07222cda6f0d895171dba78c8d404053d02754205a8234e3c37ee6a
+		1577635cad
+	"
+	);
+
+	const RAW_EIP2930_TRANSACTION: [u8; 166] = hex!(
+		"
+		01f8a3010a8504a817c8008275309435353535353535353535353535353535353535350184deadbe
+		eff838f7943535353535353535353535353535353535353535e1a000000000000000000000000000
+		0000000000000000000000000000000000000101a07592aab5d43618dda13fba71e3993cd7517a71
+		2d3da49664c06ee1bd3d1f70afa0740b39c3fd2c3af78ecc62b141370306b128d3b98d37858f21bf
+		6c7efb1ef4cc
+	"
+	);
+
+	const RAW_EIP1559_TRANSACTION: [u8; 118] = hex!(
+		"
+		02f873010b843b9aca008506fc23ac0082c350943535353535353535353535353535353535353535
+		880de0b6b3a764000080c080a0e5740e63bad28081ed7cf654dd6c19029ca03382fc05ab5f5dda81
+		f2c55b845ba00aad0d79de7070d520bb0b2fe4f1efe55feec1817ca0103b21a5109a9b931fb4
+	"
+	);
+
+	const RAW_EIP4844_TRANSACTION: [u8; 147] = hex!(
+		"
+		03f890010c843b9aca008506fc23ac0082c350943535353535353535353535353535353535353535
+		80820102c001e1a00100000000000000000000000000000000000000000000000000000000000000
+		80a0ec6d499aefd540e90357f1004a136049d1f7df5ad99c44c46e3ed4169e40acb6a011a063a20a
+		1a3312a7d218d96331d8c92803a2d24c21f2ea2f5817d5bda19edb
+	"
+	);
+
+	#[test]
+	fn decode_legacy_transactions() {
+		let transaction: Transaction = rlp::decode(&RAW_LEGACY_TRANSACTION).unwrap();
+		assert_eq!(
+			transaction,
+			Transaction {
+				tx_type: 0,
+				chain_id: Some(1),
+				nonce: 9,
+				gas_limit: 21000,
+				to: Some(RECIPIENT.into()),
+				value: U256::exp10(18),
+				input: vec![],
+				sender: SENDER.into(),
+			}
+		);
+
+		let transaction: Transaction = rlp::decode(&RAW_UNPROTECTED_TRANSACTION).unwrap();
+		assert_eq!(transaction.chain_id, None);
+		assert_eq!(transaction.nonce, 13);
+		assert_eq!(transaction.to, None);
+		assert_eq!(transaction.input, hex!("6000"));
+		assert_eq!(transaction.sender, SENDER.into());
+	}
+
+	#[test]
+	fn decode_typed_transactions() {
+		let transaction: Transaction = rlp::decode(&RAW_EIP2930_TRANSACTION).unwrap();
+		assert_eq!(
+			transaction,
+			Transaction {
+				tx_type: 1,
+				chain_id: Some(1),
+				nonce: 10,
+				gas_limit: 30000,
+				to: Some(RECIPIENT.into()),
+				value: U256::one(),
+				input: hex!("deadbeef").to_vec(),
+				sender: SENDER.into(),
+			}
+		);
+
+		let transaction: Transaction = rlp::decode(&RAW_EIP1559_TRANSACTION).unwrap();
+		assert_eq!((transaction.tx_type, transaction.nonce), (2, 11));
+		assert_eq!(transaction.gas_limit, 50000);
+		assert_eq!(transaction.value, U256::exp10(18));
+		assert_eq!(transaction.sender, SENDER.into());
+
+		let transaction: Transaction = rlp::decode(&RAW_EIP4844_TRANSACTION).unwrap();
+		assert_eq!((transaction.tx_type, transaction.nonce), (3, 12));
+		assert_eq!(transaction.to, Some(RECIPIENT.into()));
+		assert_eq!(transaction.input, hex!("0102"));
+		assert_eq!(transaction.sender, SENDER.into());
+	}
+
+	#[test]
+	fn decode_tampered_transaction() {
+		// Changing the value changes the recovered sender
+		let mut raw = RAW_EIP1559_TRANSACTION;
+		raw[41] = 0x0e;
+		let transaction: Transaction = rlp::decode(&raw).unwrap();
+		assert_ne!(transaction.sender, H160::from(SENDER));
+	}
+
+	#[test]
+	fn decode_unsupported_transaction_type() {
+		let raw = [&[4u8], &RAW_EIP4844_TRANSACTION[1..]].concat();
+		assert_eq!(
+			rlp::decode::<Transaction>(&raw),
+			Err(rlp::DecoderError::Custom("Unsupported transaction type"))
+		);
+		// An EIP-1559 payload with the type of an EIP-4844 transaction
+		let raw = [&[3u8], &RAW_EIP1559_TRANSACTION[1..]].concat();
+		assert_eq!(rlp::decode::<Transaction>(&raw), Err(rlp::DecoderError::RlpIncorrectListLen));
+	}
+}
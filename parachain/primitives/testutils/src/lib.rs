@@ -4,6 +4,8 @@ use ethereum_types::{H128, H256, H512};
 use serde::{Deserialize, Deserializer};
 use std::{fs::File, path::Path};
 
+pub mod state_proof;
+
 /// The structs defined below are used to load Ethash merkle proofs
 /// generated by https://github.com/talbaneth/ethashproof.
 /// To generate proof JSON:
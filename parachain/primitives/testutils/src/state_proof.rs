@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+//! State trie proof of a contract account and storage trie proof of one of its slots, generated
+//! with a reference trie builder.
+use hex_literal::hex;
+
+pub const STATE_ROOT: [u8; 32] =
+	hex!("567c6d993998b52e521c7d58017affea6db4db061bb9c08b23ed198b12cfc2a7");
+pub const STORAGE_ROOT: [u8; 32] =
+	hex!("51a8609bdccdeae53507f38a8d03747920d2836baecbd6b995404cf2270538b3");
+pub const CONTRACT_ADDRESS: [u8; 20] = hex!("0553b0185a35cd5bb6386747517ef7e53b15e287");
+// keccak256 of CONTRACT_ADDRESS
+pub const ACCOUNT_KEY: [u8; 32] =
+	hex!("02f902a76a5e20530c40a4954c3696af7bc4fc4c566f09c976d9743cf5770b76");
+// keccak256 of the address 7d6b0d1afbb12380d709547e10b7ecdf7710be1d, which has no account
+pub const ABSENT_ACCOUNT_KEY: [u8; 32] =
+	hex!("d0d7261531f96056fc08db00deaefbdacf8ee7be0520dbc61a2c5031a2cc7e22");
+// keccak256 of storage slot 2
+pub const STORAGE_KEY: [u8; 32] =
+	hex!("405787fa12a823e0f2b7631cc41b3ba8828b3321ca811111fa75cd3aa3bb5ace");
+// keccak256 of storage slot 3, which is not set
+pub const ABSENT_STORAGE_KEY: [u8; 32] =
+	hex!("c2575a0e9e593c00f959f8c92f12db2869c3395a3b0502d05e2516446f71f85b");
+
+pub const ACCOUNT_PROOF_0: [u8; 243] = hex!(
+	"
+	f8f1a0186bc500f3861ae8f8e7d66f8874c7d12024c0b9c0533147f846f1fc9cc71959a01fb7868d
+	69c13f316af04bba6169f29ac785d78db643a33b1b8ffb71783bba10808080808080a066c7571570
+	ce58bd02868fc1aff14871bd54cb0509208f79a0f82ab404a82b0180a0ba5bab390f85a1bd072a1b
+	a674f9497677cb5dbb9e0d6fb7fd557114d8a1447c80a0f157560df1748702ccd0aed701c0cf55d0
+	e97eb1cca693fb8ee4a143006e8654a06bcd496fef9824b4d95aedaeeacd0cf05254735db63e835c
+	08f4f47a76464ab6a09a1113d862757150c1d9194730923ebc569ab385e7a4334e5ce8404a57cf2a
+	028080
+"
+);
+pub const ACCOUNT_PROOF_1: [u8; 83] = hex!(
+	"
+	f8518080a04fdb9d44642c26206c48fe9d7bd651e505f19d80167606bd7bfe57d370e2d42b808080
+	808080a05fab1b014ad4b17d81387a79fee6cb67454ad3612dcf2f91ad89947fc982503180808080
+	808080
+"
+);
+pub const ACCOUNT_PROOF_2: [u8; 107] = hex!(
+	"
+	f869a020f902a76a5e20530c40a4954c3696af7bc4fc4c566f09c976d9743cf5770b76b846f84401
+	80a051a8609bdccdeae53507f38a8d03747920d2836baecbd6b995404cf2270538b3a02dc081a8d6
+	d4714c79b5abd2e9b08c3a33b4ef1dcf946ef8b8cf6c495014f47b
+"
+);
+
+pub const STORAGE_PROOF_0: [u8; 147] = hex!(
+	"
+	f891a0c8f1ec5dc23527c69201d88f7bc6b9790f1a42d8f040c41e065bfbf0919bc27d80a0f182bb
+	f63559b43f21d7a276844cf548d6772d7372a32d131e5fc27ee571d23780a0da12d743b4aaf695ae
+	778b9e59ab0eff31ea8d1c042f52771d8abc84c2c20fc4808080808080a0107fa2f10231df59598f
+	8a6787f84ad9943e8535cc92157d2a8eed68a33a24dd8080808080
+"
+);
+pub const STORAGE_PROOF_1: [u8; 63] = hex!(
+	"
+	f83da0305787fa12a823e0f2b7631cc41b3ba8828b3321ca811111fa75cd3aa3bb5ace9b9a010000
+	0000000000000000000000000000000000000000000007
+"
+);
+
+pub const ABSENT_ACCOUNT_PROOF_1: [u8; 115] = hex!(
+	"
+	f871a0362f0eb169afcefd5787947d171a691e893878e245ce52d8468ede6df47ea7b0b84ef84c04
+	884563918244f40000a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363
+	b421a0c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470
+"
+);
+
+pub fn account_proof() -> Vec<Vec<u8>> {
+	vec![ACCOUNT_PROOF_0.to_vec(), ACCOUNT_PROOF_1.to_vec(), ACCOUNT_PROOF_2.to_vec()]
+}
+
+pub fn storage_proof() -> Vec<Vec<u8>> {
+	vec![STORAGE_PROOF_0.to_vec(), STORAGE_PROOF_1.to_vec()]
+}
@@ -4,8 +4,7 @@
 package ethereum
 
 import (
-	"bytes"
-	"encoding/hex"
+	"fmt"
 
 	etypes "github.com/ethereum/go-ethereum/core/types"
 	"github.com/ethereum/go-ethereum/rlp"
@@ -18,39 +17,78 @@ import (
 )
 
 func MakeMessageFromEvent(event *etypes.Log, receiptsTrie *etrie.Trie) (*parachain.Message, error) {
-	// RLP encode event log's Address, Topics, and Data
-	var buf bytes.Buffer
-	err := event.EncodeRLP(&buf)
+	receiptKey, err := rlp.EncodeToBytes(event.TxIndex)
 	if err != nil {
 		return nil, err
 	}
 
-	receiptKey, err := rlp.EncodeToBytes(event.TxIndex)
+	proof := parachain.NewProofData()
+	err = receiptsTrie.Prove(receiptKey, 0, proof)
 	if err != nil {
 		return nil, err
 	}
 
-	proof := parachain.NewProofData()
-	err = receiptsTrie.Prove(receiptKey, 0, proof)
+	logIndex, err := receiptLogIndex(event, receiptsTrie)
 	if err != nil {
 		return nil, err
 	}
 
+	// The execution header is imported by the beacon relay beforehand, so it is not proven inline
 	m := parachain.Message{
-		Data: buf.Bytes(),
 		Proof: parachain.Proof{
-			BlockHash: types.NewH256(event.BlockHash.Bytes()),
-			TxIndex:   types.NewU32(uint32(event.TxIndex)),
-			Data:      proof,
+			BlockHash:      types.NewH256(event.BlockHash.Bytes()),
+			TxIndex:        types.NewU32(uint32(event.TxIndex)),
+			LogIndex:       types.NewU32(logIndex),
+			Data:           proof,
+			ExecutionProof: parachain.OptionExecutionProof{HasValue: false},
 		},
 	}
 
-	value := hex.EncodeToString(m.Data)
 	log.WithFields(logrus.Fields{
-		"payload":    value,
-		"blockHash":  m.Proof.BlockHash.Hex(),
-		"eventIndex": m.Proof.TxIndex,
+		"blockHash": m.Proof.BlockHash.Hex(),
+		"txIndex":   m.Proof.TxIndex,
+		"logIndex":  m.Proof.LogIndex,
 	}).Debug("Generated message from Ethereum log")
 
 	return &m, nil
 }
+
+// receiptLogIndex returns the index of the event log within its receipt. Ethereum logs are
+// indexed within the block, so the logs of the receipts preceding it are deducted.
+func receiptLogIndex(event *etypes.Log, receiptsTrie *etrie.Trie) (uint32, error) {
+	receipt := func(txIndex uint) (*etypes.Receipt, error) {
+		key, err := rlp.EncodeToBytes(txIndex)
+		if err != nil {
+			return nil, err
+		}
+		value, err := receiptsTrie.TryGet(key)
+		if err != nil {
+			return nil, err
+		}
+		var decoded etypes.Receipt
+		err = decoded.UnmarshalBinary(value)
+		if err != nil {
+			return nil, err
+		}
+		return &decoded, nil
+	}
+
+	precedingLogs := uint(0)
+	for txIndex := uint(0); txIndex < event.TxIndex; txIndex++ {
+		r, err := receipt(txIndex)
+		if err != nil {
+			return 0, err
+		}
+		precedingLogs += uint(len(r.Logs))
+	}
+
+	r, err := receipt(event.TxIndex)
+	if err != nil {
+		return 0, err
+	}
+	if event.Index < precedingLogs || event.Index-precedingLogs >= uint(len(r.Logs)) {
+		return 0, fmt.Errorf("log %d is not in the receipt of transaction %d", event.Index, event.TxIndex)
+	}
+
+	return uint32(event.Index - precedingLogs), nil
+}
@@ -60,6 +60,8 @@ func TestMessage_Proof(t *testing.T) {
 	assert.NotNil(t, msg)
 
 	assert.Equal(t, block.Hash().Hex(), msg.Proof.BlockHash.Hex())
+	assert.Equal(t, uint32(5), uint32(msg.Proof.LogIndex))
+	assert.False(t, msg.Proof.ExecutionProof.HasValue)
 	key, err := rlp.EncodeToBytes(uint(msg.Proof.TxIndex))
 	if err != nil {
 		panic(err)
@@ -7,18 +7,37 @@ import (
 	"fmt"
 
 	gethCommon "github.com/ethereum/go-ethereum/common"
+	gsrpcScale "github.com/snowfork/go-substrate-rpc-client/v4/scale"
 	"github.com/snowfork/go-substrate-rpc-client/v4/types"
+	"github.com/snowfork/snowbridge/relayer/relays/beacon/header/syncer/scale"
 )
 
+// Message is the log at Proof.LogIndex in the receipt proven by Proof.
 type Message struct {
-	Data  []byte
 	Proof Proof
 }
 
 type Proof struct {
-	BlockHash types.H256
-	TxIndex   types.U32
-	Data      *ProofData
+	BlockHash      types.H256
+	TxIndex        types.U32
+	LogIndex       types.U32
+	Data           *ProofData
+	ExecutionProof OptionExecutionProof
+}
+
+// OptionExecutionProof proves the execution header of Proof.BlockHash inline, when it has not
+// been imported into the beacon light client beforehand.
+type OptionExecutionProof struct {
+	HasValue bool
+	Value    scale.HeaderUpdatePayload
+}
+
+func (o OptionExecutionProof) Encode(encoder gsrpcScale.Encoder) error {
+	return encoder.EncodeOption(o.HasValue, o.Value)
+}
+
+func (o *OptionExecutionProof) Decode(decoder gsrpcScale.Decoder) error {
+	return decoder.DecodeOption(&o.HasValue, &o.Value)
 }
 
 type ProofData struct {
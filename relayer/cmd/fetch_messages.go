@@ -20,7 +20,6 @@ import (
 	gethTrie "github.com/ethereum/go-ethereum/trie"
 	"github.com/snowfork/go-substrate-rpc-client/v4/types"
 	"github.com/snowfork/snowbridge/relayer/chain/ethereum"
-	"github.com/snowfork/snowbridge/relayer/chain/parachain"
 	"github.com/snowfork/snowbridge/relayer/contracts"
 )
 
@@ -159,10 +158,7 @@ func printEthContractEventForSub(event *gethTypes.Log, trie *gethTrie.Trie) erro
 		return err
 	}
 
-	msgInner, ok := message.Args[0].(parachain.Message)
-	if !ok {
-		return err
-	}
+	msgInner := *message
 
 	formatProofVec := func(data []types.Bytes) string {
 		hexRep := make([]string, len(data))
@@ -177,19 +173,20 @@ func printEthContractEventForSub(event *gethTypes.Log, trie *gethTrie.Trie) erro
 	fmt.Println("")
 	fmt.Printf(
 		`Message {
-			data: hex!("%s").to_vec(),
 			proof: Proof {
 				block_hash: hex!("%x").into(),
 				tx_index: %d,
+				log_index: %d,
 				data: (
 					%s,
 					%s,
 				),
+				execution_proof: None,
 			},
 		}`,
-		hex.EncodeToString(msgInner.Data),
 		msgInner.Proof.BlockHash,
 		msgInner.Proof.TxIndex,
+		msgInner.Proof.LogIndex,
 		formatProofVec(msgInner.Proof.Data.Keys),
 		formatProofVec(msgInner.Proof.Data.Values),
 	)
@@ -1,6 +1,7 @@
 package api
 
 import (
+	"bytes"
 	"encoding/hex"
 	"fmt"
 	"math/big"
@@ -10,6 +11,9 @@ import (
 	"github.com/snowfork/snowbridge/relayer/relays/beacon/config"
 
 	"github.com/ethereum/go-ethereum/common"
+	gethTypes "github.com/ethereum/go-ethereum/core/types"
+	"github.com/ethereum/go-ethereum/rlp"
+	"github.com/ethereum/go-ethereum/trie"
 	"github.com/snowfork/go-substrate-rpc-client/v4/types"
 	beaconjson "github.com/snowfork/snowbridge/relayer/relays/beacon/header/syncer/json"
 	"github.com/snowfork/snowbridge/relayer/relays/beacon/header/syncer/scale"
@@ -1403,6 +1407,45 @@ func CapellaExecutionPayloadToScale(e *state.ExecutionPayloadCapella, activeSpec
 	}, nil
 }
 
+// ExecutionBlockRootsToScale computes the trie roots of the transactions and withdrawals in the
+// execution payload, which the execution block header commits to instead of their SSZ roots.
+func ExecutionBlockRootsToScale(e *state.ExecutionPayloadCapella) scale.ExecutionBlockRoots {
+	transactionsRoot := gethTypes.DeriveSha(rawTransactions(e.Transactions), trie.NewStackTrie(nil))
+	withdrawalsRoot := gethTypes.DeriveSha(withdrawals(e.Withdrawals), trie.NewStackTrie(nil))
+
+	return scale.ExecutionBlockRoots{
+		TransactionsRoot: types.NewH256(transactionsRoot.Bytes()),
+		WithdrawalsRoot:  types.NewH256(withdrawalsRoot.Bytes()),
+	}
+}
+
+// rawTransactions are already encoded the way they are stored in the transactions trie.
+type rawTransactions [][]byte
+
+func (t rawTransactions) Len() int {
+	return len(t)
+}
+
+func (t rawTransactions) EncodeIndex(i int, w *bytes.Buffer) {
+	w.Write(t[i])
+}
+
+type withdrawals []*state.Withdrawal
+
+func (ws withdrawals) Len() int {
+	return len(ws)
+}
+
+func (ws withdrawals) EncodeIndex(i int, w *bytes.Buffer) {
+	withdrawal := ws[i]
+	_ = rlp.Encode(w, []interface{}{
+		withdrawal.Index,
+		withdrawal.ValidatorIndex,
+		common.Address(withdrawal.Address),
+		withdrawal.Amount,
+	})
+}
+
 func SyncAggregateToScale(s state.SyncAggregate) scale.SyncAggregate {
 	aggregateSignature := s.GetSyncAggregateSignature()
 	return scale.SyncAggregate{
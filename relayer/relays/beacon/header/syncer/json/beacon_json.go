@@ -5,12 +5,13 @@ import (
 )
 
 type CheckPoint struct {
-	Header                     BeaconHeader  `json:"header"`
-	CurrentSyncCommittee       SyncCommittee `json:"current_sync_committee"`
-	CurrentSyncCommitteeBranch []string      `json:"current_sync_committee_branch"`
-	ValidatorsRoot             string        `json:"validators_root"`
-	BlockRootsRoot             string        `json:"block_roots_root"`
-	BlockRootsBranch           []string      `json:"block_roots_branch"`
+	Header                     BeaconHeader             `json:"header"`
+	CurrentSyncCommittee       SyncCommittee            `json:"current_sync_committee"`
+	CurrentSyncCommitteeBranch []string                 `json:"current_sync_committee_branch"`
+	ValidatorsRoot             string                   `json:"validators_root"`
+	BlockRootsRoot             string                   `json:"block_roots_root"`
+	BlockRootsBranch           []string                 `json:"block_roots_branch"`
+	NextSyncCommitteeUpdate    *NextSyncCommitteeUpdate `json:"next_sync_committee_update,omitempty"`
 }
 
 type BeaconHeader struct {
@@ -107,6 +108,33 @@ type ExecutionPayloadHeaderCapella struct {
 	WithdrawalsRoot string `json:"withdrawals_root"`
 }
 
+type ExecutionPayloadHeaderDeneb struct {
+	ParentHash      string `json:"parent_hash"`
+	FeeRecipient    string `json:"fee_recipient"`
+	StateRoot       string `json:"state_root"`
+	ReceiptsRoot    string `json:"receipts_root"`
+	LogsBloom       string `json:"logs_bloom"`
+	PrevRandao      string `json:"prev_randao"`
+	BlockNumber     uint64 `json:"block_number"`
+	GasLimit        uint64 `json:"gas_limit"`
+	GasUsed         uint64 `json:"gas_used"`
+	Timestamp       uint64 `json:"timestamp"`
+	ExtraData       string `json:"extra_data"`
+	BaseFeePerGas   uint64 `json:"base_fee_per_gas"`
+	BlockHash       string `json:"block_hash"`
+	TransactionRoot string `json:"transactions_root"`
+	WithdrawalsRoot string `json:"withdrawals_root"`
+	BlobGasUsed     uint64 `json:"blob_gas_used"`
+	ExcessBlobGas   uint64 `json:"excess_blob_gas"`
+}
+
+// VersionedExecutionPayloadHeader is keyed by the fork of the execution payload header, of which
+// exactly one is set.
+type VersionedExecutionPayloadHeader struct {
+	Capella *ExecutionPayloadHeaderCapella `json:"Capella,omitempty"`
+	Deneb   *ExecutionPayloadHeaderDeneb   `json:"Deneb,omitempty"`
+}
+
 type Eth1Data struct {
 	DepositRoot  string `json:"deposit_root"`
 	DepositCount uint64 `json:"deposit_count"`
@@ -127,17 +155,38 @@ type BlockBody struct {
 }
 
 type HeaderUpdate struct {
-	Header          BeaconHeader                  `json:"header"`
-	AncestryProof   *AncestryProof                `json:"ancestry_proof"`
-	ExecutionHeader ExecutionPayloadHeaderCapella `json:"execution_header"`
-	ExecutionBranch []string                      `json:"execution_branch"`
+	Header              BeaconHeader                    `json:"header"`
+	AncestryProof       *AncestryProof                  `json:"ancestry_proof"`
+	ExecutionHeader     VersionedExecutionPayloadHeader `json:"execution_header"`
+	ExecutionBranch     []string                        `json:"execution_branch"`
+	ExecutionBlockRoots ExecutionBlockRoots             `json:"execution_block_roots"`
+}
+
+// ExecutionBlockRoots has an empty RequestsHash for execution blocks before the Electra fork.
+type ExecutionBlockRoots struct {
+	TransactionsRoot string `json:"transactions_root"`
+	WithdrawalsRoot  string `json:"withdrawals_root"`
+	RequestsHash     string `json:"requests_hash,omitempty"`
 }
 
+// AncestryProof is keyed by the kind of ancestry proof, of which exactly one is set.
 type AncestryProof struct {
+	BlockRoots          *BlockRootsAncestryProof          `json:"block_roots,omitempty"`
+	HistoricalSummaries *HistoricalSummariesAncestryProof `json:"historical_summaries,omitempty"`
+}
+
+type BlockRootsAncestryProof struct {
 	HeaderBranch       []string `json:"header_branch"`
 	FinalizedBlockRoot string   `json:"finalized_block_root"`
 }
 
+type HistoricalSummariesAncestryProof struct {
+	HeaderBranch           []string     `json:"header_branch"`
+	BlockSummaryRoot       string       `json:"block_summary_root"`
+	BlockSummaryRootBranch []string     `json:"block_summary_root_branch"`
+	FinalizedHeader        BeaconHeader `json:"finalized_header"`
+}
+
 type Attestation struct {
 	AggregationBits string          `json:"aggregation_bits"`
 	Data            AttestationData `json:"data"`
@@ -263,6 +312,28 @@ func (e *ExecutionPayloadHeaderCapella) RemoveLeadingZeroHashes() {
 	e.WithdrawalsRoot = removeLeadingZeroHash(e.WithdrawalsRoot)
 }
 
+func (e *ExecutionPayloadHeaderDeneb) RemoveLeadingZeroHashes() {
+	e.ParentHash = removeLeadingZeroHash(e.ParentHash)
+	e.FeeRecipient = removeLeadingZeroHash(e.FeeRecipient)
+	e.StateRoot = removeLeadingZeroHash(e.StateRoot)
+	e.ReceiptsRoot = removeLeadingZeroHash(e.ReceiptsRoot)
+	e.LogsBloom = removeLeadingZeroHash(e.LogsBloom)
+	e.PrevRandao = removeLeadingZeroHash(e.PrevRandao)
+	e.ExtraData = removeLeadingZeroHash(e.ExtraData)
+	e.BlockHash = removeLeadingZeroHash(e.BlockHash)
+	e.TransactionRoot = removeLeadingZeroHash(e.TransactionRoot)
+	e.WithdrawalsRoot = removeLeadingZeroHash(e.WithdrawalsRoot)
+}
+
+func (v *VersionedExecutionPayloadHeader) RemoveLeadingZeroHashes() {
+	if v.Capella != nil {
+		v.Capella.RemoveLeadingZeroHashes()
+	}
+	if v.Deneb != nil {
+		v.Deneb.RemoveLeadingZeroHashes()
+	}
+}
+
 func (i *CheckPoint) RemoveLeadingZeroHashes() {
 	i.Header.RemoveLeadingZeroHashes()
 	i.CurrentSyncCommittee.RemoveLeadingZeroHashes()
@@ -274,6 +345,10 @@ func (i *CheckPoint) RemoveLeadingZeroHashes() {
 	i.ValidatorsRoot = removeLeadingZeroHash(i.ValidatorsRoot)
 	i.BlockRootsRoot = removeLeadingZeroHash(i.BlockRootsRoot)
 	i.BlockRootsBranch = removeLeadingZeroHashForSlice(i.BlockRootsBranch)
+	if i.NextSyncCommitteeUpdate != nil {
+		i.NextSyncCommitteeUpdate.NextSyncCommittee.RemoveLeadingZeroHashes()
+		i.NextSyncCommitteeUpdate.NextSyncCommitteeBranch = removeLeadingZeroHashForSlice(i.NextSyncCommitteeUpdate.NextSyncCommitteeBranch)
+	}
 }
 
 func (s *Update) RemoveLeadingZeroHashes() {
@@ -293,11 +368,26 @@ func (s *Update) RemoveLeadingZeroHashes() {
 func (h *HeaderUpdate) RemoveLeadingZeroHashes() {
 	h.Header.RemoveLeadingZeroHashes()
 	if h.AncestryProof != nil {
-		h.AncestryProof.HeaderBranch = removeLeadingZeroHashForSlice(h.AncestryProof.HeaderBranch)
-		h.AncestryProof.FinalizedBlockRoot = removeLeadingZeroHash(h.AncestryProof.FinalizedBlockRoot)
+		h.AncestryProof.RemoveLeadingZeroHashes()
 	}
 	h.ExecutionHeader.RemoveLeadingZeroHashes()
 	h.ExecutionBranch = removeLeadingZeroHashForSlice(h.ExecutionBranch)
+	h.ExecutionBlockRoots.TransactionsRoot = removeLeadingZeroHash(h.ExecutionBlockRoots.TransactionsRoot)
+	h.ExecutionBlockRoots.WithdrawalsRoot = removeLeadingZeroHash(h.ExecutionBlockRoots.WithdrawalsRoot)
+	h.ExecutionBlockRoots.RequestsHash = removeLeadingZeroHash(h.ExecutionBlockRoots.RequestsHash)
+}
+
+func (a *AncestryProof) RemoveLeadingZeroHashes() {
+	if a.BlockRoots != nil {
+		a.BlockRoots.HeaderBranch = removeLeadingZeroHashForSlice(a.BlockRoots.HeaderBranch)
+		a.BlockRoots.FinalizedBlockRoot = removeLeadingZeroHash(a.BlockRoots.FinalizedBlockRoot)
+	}
+	if a.HistoricalSummaries != nil {
+		a.HistoricalSummaries.HeaderBranch = removeLeadingZeroHashForSlice(a.HistoricalSummaries.HeaderBranch)
+		a.HistoricalSummaries.BlockSummaryRoot = removeLeadingZeroHash(a.HistoricalSummaries.BlockSummaryRoot)
+		a.HistoricalSummaries.BlockSummaryRootBranch = removeLeadingZeroHashForSlice(a.HistoricalSummaries.BlockSummaryRootBranch)
+		a.HistoricalSummaries.FinalizedHeader.RemoveLeadingZeroHashes()
+	}
 }
 
 func removeLeadingZeroHashForSlice(s []string) []string {
@@ -23,6 +23,7 @@ type BeaconCheckpoint struct {
 	ValidatorsRoot             types.H256
 	BlockRootsRoot             types.H256
 	BlockRootsBranch           []types.H256
+	NextSyncCommitteeUpdate    OptionNextSyncCommitteeUpdatePayload
 }
 
 type Update struct {
@@ -63,8 +64,30 @@ func (o *OptionNextSyncCommitteeUpdatePayload) Decode(decoder scale.Decoder) err
 type HeaderUpdatePayload struct {
 	Header          BeaconHeader
 	AncestryProof   OptionAncestryProof
-	ExecutionHeader ExecutionPayloadHeaderCapella
-	ExecutionBranch []types.H256
+	ExecutionHeader     VersionedExecutionPayloadHeader
+	ExecutionBranch     []types.H256
+	ExecutionBlockRoots ExecutionBlockRoots
+}
+
+// ExecutionBlockRoots are the trie roots that the execution block header commits to, from which
+// the beacon client recomputes the execution block hash.
+type ExecutionBlockRoots struct {
+	TransactionsRoot types.H256
+	WithdrawalsRoot  types.H256
+	RequestsHash     OptionH256
+}
+
+type OptionH256 struct {
+	HasValue bool
+	Value    types.H256
+}
+
+func (o OptionH256) Encode(encoder scale.Encoder) error {
+	return encoder.EncodeOption(o.HasValue, o.Value)
+}
+
+func (o *OptionH256) Decode(decoder scale.Decoder) error {
+	return decoder.DecodeOption(&o.HasValue, &o.Value)
 }
 
 type OptionAncestryProof struct {
@@ -72,11 +95,64 @@ type OptionAncestryProof struct {
 	Value    AncestryProof
 }
 
+// AncestryProof proves that a header is an ancestor of a finalized header, through either the
+// block roots or the historical summaries of the finalized header. Exactly one of the fields is
+// set.
 type AncestryProof struct {
+	BlockRoots          *BlockRootsAncestryProof
+	HistoricalSummaries *HistoricalSummariesAncestryProof
+}
+
+type BlockRootsAncestryProof struct {
 	HeaderBranch       []types.H256
 	FinalizedBlockRoot types.H256
 }
 
+type HistoricalSummariesAncestryProof struct {
+	HeaderBranch           []types.H256
+	BlockSummaryRoot       types.H256
+	BlockSummaryRootBranch []types.H256
+	FinalizedHeader        BeaconHeader
+}
+
+func (a AncestryProof) Encode(encoder scale.Encoder) error {
+	var err error
+	switch {
+	case a.BlockRoots != nil:
+		err = encoder.PushByte(0)
+		if err != nil {
+			return err
+		}
+		err = encoder.Encode(*a.BlockRoots)
+	case a.HistoricalSummaries != nil:
+		err = encoder.PushByte(1)
+		if err != nil {
+			return err
+		}
+		err = encoder.Encode(*a.HistoricalSummaries)
+	default:
+		return fmt.Errorf("ancestry proof kind not set")
+	}
+	return err
+}
+
+func (a *AncestryProof) Decode(decoder scale.Decoder) error {
+	tag, err := decoder.ReadOneByte()
+	if err != nil {
+		return err
+	}
+	switch tag {
+	case 0:
+		a.BlockRoots = &BlockRootsAncestryProof{}
+		return decoder.Decode(a.BlockRoots)
+	case 1:
+		a.HistoricalSummaries = &HistoricalSummariesAncestryProof{}
+		return decoder.Decode(a.HistoricalSummaries)
+	default:
+		return fmt.Errorf("invalid ancestry proof kind: %d", tag)
+	}
+}
+
 func (o OptionAncestryProof) Encode(encoder scale.Encoder) error {
 	return encoder.EncodeOption(o.HasValue, o.Value)
 }
@@ -190,6 +266,71 @@ type ExecutionPayloadHeaderCapella struct {
 	WithdrawalsRoot  types.H256
 }
 
+type ExecutionPayloadHeaderDeneb struct {
+	ParentHash       types.H256
+	FeeRecipient     types.H160
+	StateRoot        types.H256
+	ReceiptsRoot     types.H256
+	LogsBloom        []byte
+	PrevRandao       types.H256
+	BlockNumber      types.U64
+	GasLimit         types.U64
+	GasUsed          types.U64
+	Timestamp        types.U64
+	ExtraData        []byte
+	BaseFeePerGas    types.U256
+	BlockHash        types.H256
+	TransactionsRoot types.H256
+	WithdrawalsRoot  types.H256
+	BlobGasUsed      types.U64
+	ExcessBlobGas    types.U64
+}
+
+// VersionedExecutionPayloadHeader is the execution payload header of the fork active at the
+// slot of the beacon header containing it. Exactly one of the fields is set.
+type VersionedExecutionPayloadHeader struct {
+	Capella *ExecutionPayloadHeaderCapella
+	Deneb   *ExecutionPayloadHeaderDeneb
+}
+
+func (v VersionedExecutionPayloadHeader) Encode(encoder scale.Encoder) error {
+	var err error
+	switch {
+	case v.Capella != nil:
+		err = encoder.PushByte(0)
+		if err != nil {
+			return err
+		}
+		err = encoder.Encode(*v.Capella)
+	case v.Deneb != nil:
+		err = encoder.PushByte(1)
+		if err != nil {
+			return err
+		}
+		err = encoder.Encode(*v.Deneb)
+	default:
+		return fmt.Errorf("execution payload header version not set")
+	}
+	return err
+}
+
+func (v *VersionedExecutionPayloadHeader) Decode(decoder scale.Decoder) error {
+	tag, err := decoder.ReadOneByte()
+	if err != nil {
+		return err
+	}
+	switch tag {
+	case 0:
+		v.Capella = &ExecutionPayloadHeaderCapella{}
+		return decoder.Decode(v.Capella)
+	case 1:
+		v.Deneb = &ExecutionPayloadHeaderDeneb{}
+		return decoder.Decode(v.Deneb)
+	default:
+		return fmt.Errorf("invalid execution payload header version: %d", tag)
+	}
+}
+
 type Body struct {
 	RandaoReveal      []byte
 	Eth1Data          Eth1Data
@@ -225,56 +366,20 @@ type BeaconBlock struct {
 	Body          Body
 }
 
+// SyncCommittee is SCALE encoded with a length prefix for the public keys, since the size of the
+// sync committee depends on the preset.
 type SyncCommittee struct {
 	Pubkeys         [][48]byte
 	AggregatePubkey [48]byte
 }
 
-// Use a custom SCALE encoder to encode SyncCommitteeBits as fixed array
-func (s SyncCommittee) Encode(encoder scale.Encoder) error {
-
-	switch len(s.Pubkeys) {
-	case 32:
-		var pubkeys [32][48]byte
-		copy(pubkeys[:], s.Pubkeys)
-		encoder.Encode(pubkeys)
-	case 512:
-		var pubkeys [512][48]byte
-		copy(pubkeys[:], s.Pubkeys)
-		encoder.Encode(pubkeys)
-	default:
-		return fmt.Errorf("invalid sync committee size")
-	}
-	encoder.Encode(s.AggregatePubkey)
-	return nil
-}
-
+// SyncAggregate is SCALE encoded with a length prefix for the participation bits, since the size
+// of the sync committee depends on the preset.
 type SyncAggregate struct {
 	SyncCommitteeBits      []byte
 	SyncCommitteeSignature [96]byte
 }
 
-// Use a custom SCALE encoder to encode SyncCommitteeBits as fixed array
-func (s SyncAggregate) Encode(encoder scale.Encoder) error {
-
-	switch len(s.SyncCommitteeBits) {
-	case 4:
-		//	32 / 8 = 4
-		var syncCommitteeBits [4]byte
-		copy(syncCommitteeBits[:], s.SyncCommitteeBits)
-		encoder.Encode(syncCommitteeBits)
-	case 64:
-		//	512 / 8 = 64
-		var syncCommitteeBits [64]byte
-		copy(syncCommitteeBits[:], s.SyncCommitteeBits)
-		encoder.Encode(syncCommitteeBits)
-	default:
-		return fmt.Errorf("invalid sync committee size")
-	}
-	encoder.Encode(s.SyncCommitteeSignature)
-	return nil
-}
-
 func (b *BeaconHeader) ToSSZ() *state.BeaconBlockHeader {
 	return &state.BeaconBlockHeader{
 		Slot:          uint64(b.Slot),
@@ -13,23 +13,26 @@ func (p BeaconCheckpoint) ToJSON() json.CheckPoint {
 		ValidatorsRoot:             p.ValidatorsRoot.Hex(),
 		BlockRootsRoot:             p.BlockRootsRoot.Hex(),
 		BlockRootsBranch:           util.ScaleBranchToString(p.BlockRootsBranch),
+		NextSyncCommitteeUpdate:    p.NextSyncCommitteeUpdate.ToJSON(),
 	}
 }
 
-func (p UpdatePayload) ToJSON() json.Update {
-	var nextSyncCommitteeUpdate *json.NextSyncCommitteeUpdate
-	if p.NextSyncCommitteeUpdate.HasValue {
-		nextSyncCommitteeUpdate = &json.NextSyncCommitteeUpdate{
-			NextSyncCommittee:       p.NextSyncCommitteeUpdate.Value.NextSyncCommittee.ToJSON(),
-			NextSyncCommitteeBranch: util.ScaleBranchToString(p.NextSyncCommitteeUpdate.Value.NextSyncCommitteeBranch),
-		}
+func (o OptionNextSyncCommitteeUpdatePayload) ToJSON() *json.NextSyncCommitteeUpdate {
+	if !o.HasValue {
+		return nil
+	}
+	return &json.NextSyncCommitteeUpdate{
+		NextSyncCommittee:       o.Value.NextSyncCommittee.ToJSON(),
+		NextSyncCommitteeBranch: util.ScaleBranchToString(o.Value.NextSyncCommitteeBranch),
 	}
+}
 
+func (p UpdatePayload) ToJSON() json.Update {
 	return json.Update{
 		AttestedHeader:          p.AttestedHeader.ToJSON(),
 		SyncAggregate:           p.SyncAggregate.ToJSON(),
 		SignatureSlot:           uint64(p.SignatureSlot),
-		NextSyncCommitteeUpdate: nextSyncCommitteeUpdate,
+		NextSyncCommitteeUpdate: p.NextSyncCommitteeUpdate.ToJSON(),
 		FinalizedHeader:         p.FinalizedHeader.ToJSON(),
 		FinalityBranch:          util.ScaleBranchToString(p.FinalityBranch),
 		BlockRootsRoot:          p.BlockRootsRoot.Hex(),
@@ -40,19 +43,49 @@ func (p UpdatePayload) ToJSON() json.Update {
 func (h HeaderUpdatePayload) ToJSON() json.HeaderUpdate {
 	var ancestryProof *json.AncestryProof
 	if h.AncestryProof.HasValue {
-		ancestryProof = &json.AncestryProof{
-			HeaderBranch:       util.ScaleBranchToString(h.AncestryProof.Value.HeaderBranch),
-			FinalizedBlockRoot: h.AncestryProof.Value.FinalizedBlockRoot.Hex(),
-		}
+		proof := h.AncestryProof.Value.ToJSON()
+		ancestryProof = &proof
 	}
 	return json.HeaderUpdate{
-		Header:          h.Header.ToJSON(),
-		AncestryProof:   ancestryProof,
-		ExecutionHeader: h.ExecutionHeader.ToJSON(),
-		ExecutionBranch: util.ScaleBranchToString(h.ExecutionBranch),
+		Header:              h.Header.ToJSON(),
+		AncestryProof:       ancestryProof,
+		ExecutionHeader:     h.ExecutionHeader.ToJSON(),
+		ExecutionBranch:     util.ScaleBranchToString(h.ExecutionBranch),
+		ExecutionBlockRoots: h.ExecutionBlockRoots.ToJSON(),
+	}
+}
+
+func (r *ExecutionBlockRoots) ToJSON() json.ExecutionBlockRoots {
+	var requestsHash string
+	if r.RequestsHash.HasValue {
+		requestsHash = r.RequestsHash.Value.Hex()
+	}
+	return json.ExecutionBlockRoots{
+		TransactionsRoot: r.TransactionsRoot.Hex(),
+		WithdrawalsRoot:  r.WithdrawalsRoot.Hex(),
+		RequestsHash:     requestsHash,
 	}
 }
 
+func (a *AncestryProof) ToJSON() json.AncestryProof {
+	var ancestryProof json.AncestryProof
+	if a.BlockRoots != nil {
+		ancestryProof.BlockRoots = &json.BlockRootsAncestryProof{
+			HeaderBranch:       util.ScaleBranchToString(a.BlockRoots.HeaderBranch),
+			FinalizedBlockRoot: a.BlockRoots.FinalizedBlockRoot.Hex(),
+		}
+	}
+	if a.HistoricalSummaries != nil {
+		ancestryProof.HistoricalSummaries = &json.HistoricalSummariesAncestryProof{
+			HeaderBranch:           util.ScaleBranchToString(a.HistoricalSummaries.HeaderBranch),
+			BlockSummaryRoot:       a.HistoricalSummaries.BlockSummaryRoot.Hex(),
+			BlockSummaryRootBranch: util.ScaleBranchToString(a.HistoricalSummaries.BlockSummaryRootBranch),
+			FinalizedHeader:        a.HistoricalSummaries.FinalizedHeader.ToJSON(),
+		}
+	}
+	return ancestryProof
+}
+
 func (b *BeaconHeader) ToJSON() json.BeaconHeader {
 	return json.BeaconHeader{
 		Slot:          uint64(b.Slot),
@@ -63,6 +96,19 @@ func (b *BeaconHeader) ToJSON() json.BeaconHeader {
 	}
 }
 
+func (v *VersionedExecutionPayloadHeader) ToJSON() json.VersionedExecutionPayloadHeader {
+	var versioned json.VersionedExecutionPayloadHeader
+	if v.Capella != nil {
+		capella := v.Capella.ToJSON()
+		versioned.Capella = &capella
+	}
+	if v.Deneb != nil {
+		deneb := v.Deneb.ToJSON()
+		versioned.Deneb = &deneb
+	}
+	return versioned
+}
+
 func (e *ExecutionPayloadHeaderCapella) ToJSON() json.ExecutionPayloadHeaderCapella {
 	return json.ExecutionPayloadHeaderCapella{
 		ParentHash:      e.ParentHash.Hex(),
@@ -83,6 +129,28 @@ func (e *ExecutionPayloadHeaderCapella) ToJSON() json.ExecutionPayloadHeaderCape
 	}
 }
 
+func (e *ExecutionPayloadHeaderDeneb) ToJSON() json.ExecutionPayloadHeaderDeneb {
+	return json.ExecutionPayloadHeaderDeneb{
+		ParentHash:      e.ParentHash.Hex(),
+		FeeRecipient:    util.BytesToHexString(e.FeeRecipient[:]),
+		StateRoot:       e.StateRoot.Hex(),
+		ReceiptsRoot:    e.ReceiptsRoot.Hex(),
+		LogsBloom:       util.BytesToHexString(e.LogsBloom),
+		PrevRandao:      e.PrevRandao.Hex(),
+		BlockNumber:     uint64(e.BlockNumber),
+		GasLimit:        uint64(e.GasLimit),
+		GasUsed:         uint64(e.GasUsed),
+		Timestamp:       uint64(e.Timestamp),
+		ExtraData:       util.BytesToHexString(e.ExtraData),
+		BaseFeePerGas:   e.BaseFeePerGas.Uint64(),
+		BlockHash:       e.BlockHash.Hex(),
+		TransactionRoot: e.TransactionsRoot.Hex(),
+		WithdrawalsRoot: e.WithdrawalsRoot.Hex(),
+		BlobGasUsed:     uint64(e.BlobGasUsed),
+		ExcessBlobGas:   uint64(e.ExcessBlobGas),
+	}
+}
+
 func (s *SyncCommittee) ToJSON() json.SyncCommittee {
 	pubkeys := []string{}
 	for _, pubkeyScale := range s.Pubkeys {
@@ -81,6 +81,10 @@ func (s *Syncer) GetCheckpoint() (scale.BeaconCheckpoint, error) {
 		ValidatorsRoot:             types.H256(genesis.ValidatorsRoot),
 		BlockRootsRoot:             blockRootsProof.Leaf,
 		BlockRootsBranch:           blockRootsProof.Proof,
+		// The next sync committee is imported through a sync committee update instead.
+		NextSyncCommitteeUpdate: scale.OptionNextSyncCommitteeUpdatePayload{
+			HasValue: false,
+		},
 	}, nil
 }
 
@@ -398,6 +402,8 @@ func (s *Syncer) GetHeaderUpdate(blockRoot common.Hash, checkpoint *cache.Proof)
 	if err != nil {
 		return scale.HeaderUpdatePayload{}, err
 	}
+	executionHeader := scale.VersionedExecutionPayloadHeader{Capella: &executionPayloadScale}
+	executionBlockRoots := api.ExecutionBlockRootsToScale(block.GetExecutionPayload())
 
 	executionHeaderBranch, err := s.getExecutionHeaderBranch(block)
 	if err != nil {
@@ -411,8 +417,9 @@ func (s *Syncer) GetHeaderUpdate(blockRoot common.Hash, checkpoint *cache.Proof)
 			AncestryProof: scale.OptionAncestryProof{
 				HasValue: false,
 			},
-			ExecutionHeader: executionPayloadScale,
-			ExecutionBranch: executionHeaderBranch,
+			ExecutionHeader:     executionHeader,
+			ExecutionBranch:     executionHeaderBranch,
+			ExecutionBlockRoots: executionBlockRoots,
 		}, nil
 	}
 
@@ -431,12 +438,15 @@ func (s *Syncer) GetHeaderUpdate(blockRoot common.Hash, checkpoint *cache.Proof)
 		AncestryProof: scale.OptionAncestryProof{
 			HasValue: true,
 			Value: scale.AncestryProof{
-				HeaderBranch:       proofScale,
-				FinalizedBlockRoot: types.NewH256(checkpoint.FinalizedBlockRoot.Bytes()),
+				BlockRoots: &scale.BlockRootsAncestryProof{
+					HeaderBranch:       proofScale,
+					FinalizedBlockRoot: types.NewH256(checkpoint.FinalizedBlockRoot.Bytes()),
+				},
 			},
 		},
-		ExecutionHeader: executionPayloadScale,
-		ExecutionBranch: executionHeaderBranch,
+		ExecutionHeader:     executionHeader,
+		ExecutionBranch:     executionHeaderBranch,
+		ExecutionBlockRoots: executionBlockRoots,
 	}, nil
 }
 